@@ -0,0 +1,125 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: usize,
+    allocated: usize,
+    current: usize,
+    peak: usize,
+}
+
+// the counts are kept per thread so the progress bar's tick thread and the timeout
+// watchdog don't end up in the stats of the part they are watching
+thread_local! {
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            allocated: 0,
+            current: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counts)) {
+    // the counts are gone while the thread is being torn down, so those allocations are missed
+    let _ = COUNTS.try_with(|x| {
+        let mut counts = x.get();
+        f(&mut counts);
+        counts.peak = counts.peak.max(counts.current);
+        x.set(counts);
+    });
+}
+
+/// The system allocator, counting the allocations made through it on each thread.
+struct CountingAlloc;
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            update(|x| {
+                x.allocations += 1;
+                x.allocated += layout.size();
+                x.current += layout.size();
+            });
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            update(|x| {
+                x.allocations += 1;
+                x.allocated += layout.size();
+                x.current += layout.size();
+            });
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        // memory can be freed on a different thread to the one that allocated it
+        update(|x| x.current = x.current.saturating_sub(layout.size()));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            let old_size = layout.size();
+            update(|x| {
+                x.allocated += new_size.saturating_sub(old_size);
+                x.current = (x.current + new_size).saturating_sub(old_size);
+            });
+        }
+        new
+    }
+}
+
+/// The allocations made while a part ran, with its peak heap usage above what was
+/// already in use when it started. Only the thread the part ran on is counted, so
+/// any threads the part spawns itself are missed.
+pub struct AllocStats {
+    allocations: usize,
+    allocated: usize,
+    peak: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations of {} bytes, with a peak heap usage of {} bytes",
+            self.allocations, self.allocated, self.peak
+        )
+    }
+}
+
+/// Runs `f`, counting the allocations it makes on this thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let start = COUNTS.with(|x| {
+        let mut counts = x.get();
+        counts.peak = counts.current;
+        x.set(counts);
+        counts
+    });
+
+    let res = f();
+
+    let end = COUNTS.with(Cell::get);
+    let stats = AllocStats {
+        allocations: end.allocations - start.allocations,
+        allocated: end.allocated - start.allocated,
+        peak: end.peak.saturating_sub(start.current),
+    };
+    (res, stats)
+}
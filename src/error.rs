@@ -1,11 +1,43 @@
-use std::io;
+use std::{process::ExitStatus, time::Duration};
 
 use thiserror::Error;
 
+use crate::utils::ArgValue;
+
 #[derive(Error, Debug)]
 pub enum DayCommandError {
     #[error("command part {0} not found")]
     CommandPartNotFound(String),
+
+    #[error("variant {0} not found")]
+    VariantNotFound(String),
+}
+
+#[derive(Error, Debug)]
+pub enum YearCommandError {
+    #[error("day {0} not found")]
+    DayNotFound(String),
+
+    #[error("year {0} not found")]
+    YearNotFound(String),
+}
+
+#[derive(Error, Debug)]
+pub enum YearCommandBuilderError {
+    #[error("name was not specified")]
+    NameNotFound,
+
+    #[error("days were not specified")]
+    DaysNotFound,
+}
+
+#[derive(Error, Debug)]
+pub enum PartArgError {
+    #[error("arg {0} was not declared by the part")]
+    NotDeclared(String),
+
+    #[error("arg {0} can not be read from {1:?}")]
+    WrongType(String, ArgValue),
 }
 
 #[derive(Error, Debug)]
@@ -15,4 +47,92 @@ pub enum DayCommandBuilderError {
 
     #[error("command parts were not specified")]
     PartsNotFound,
+
+    #[error("func was not specified")]
+    FuncNotSpecified,
+}
+
+#[derive(Error, Debug)]
+pub enum OcrError {
+    #[error("the screen is too small to hold any letters")]
+    NoLetters,
+
+    #[error("letter {0} on the screen is not recognised")]
+    UnknownGlyph(usize),
+}
+
+#[derive(Error, Debug)]
+pub enum OperationError {
+    #[error("unexpected token {0} in operation")]
+    UnexpectedToken(String),
+
+    #[error("operation ended unexpectedly")]
+    UnexpectedEnd,
+
+    #[error("operation overflowed evaluating {0}")]
+    Overflow(String),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PacketParseError {
+    #[error("unexpected character {0:?} at position {1}")]
+    UnexpectedChar(char, usize),
+
+    #[error("packet ended unexpectedly")]
+    UnexpectedEnd,
+
+    #[error("integer at position {0} is too large")]
+    IntegerOverflow(usize),
+}
+
+#[derive(Error, Debug)]
+pub enum MonkeyMathError {
+    #[error("monkey {0} was not found")]
+    UnknownMonkey(String),
+
+    #[error("arithmetic overflowed evaluating {0}")]
+    Overflow(String),
+
+    #[error("division by zero evaluating {0}")]
+    DivisionByZero(String),
+
+    #[error("monkey {0} depends on its own number")]
+    Cycle(String),
+
+    #[error("{0} has no integer solution")]
+    NoIntegerSolution(String),
+
+    #[error("{0} does not depend on exactly one side")]
+    Unsolvable(String),
+}
+
+#[derive(Error, Debug)]
+pub enum PartRunError {
+    #[error("part panicked with {0}")]
+    Panicked(String),
+
+    #[error("part timed out after {0:?}")]
+    TimedOut(Duration),
+
+    #[error("part run in a child process failed with {0}")]
+    ChildFailed(ExitStatus),
+
+    #[cfg(not(unix))]
+    #[error("memory limits are only supported on unix")]
+    MemoryLimitUnsupported,
+}
+
+#[derive(Error, Debug)]
+pub enum AllCommandError {
+    #[error("{0} of {1} parts failed")]
+    PartsFailed(usize, usize),
+}
+
+#[derive(Error, Debug)]
+pub enum CompareError {
+    #[error("variant {0} answered {1} but variant {2} answered {3}")]
+    Disagree(&'static str, String, &'static str, String),
+
+    #[error("compare runs every variant, so --variant {0} can not be given")]
+    VariantGiven(String),
 }
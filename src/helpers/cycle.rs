@@ -0,0 +1,45 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A repeating stretch of a simulation, found by [`CycleDetector`].
+#[derive(Debug, Clone, Copy)]
+pub struct Cycle {
+    pub length: usize,
+    pub delta: i64,
+}
+
+impl Cycle {
+    /// Skips as many whole cycles as fit between `step` and `target`, returning the
+    /// steps still left to simulate and how much the value grows over the skipped cycles.
+    pub fn skip(&self, step: usize, target: usize) -> (usize, i64) {
+        let remaining = target.saturating_sub(step);
+        let cycles = remaining / self.length;
+        (remaining % self.length, cycles as i64 * self.delta)
+    }
+}
+
+/// Finds cycles in a simulation by remembering when each state was first seen,
+/// along with the value being tracked (a height, a score, ...) at that step.
+pub struct CycleDetector<K> {
+    seen: HashMap<K, (usize, i64)>,
+}
+
+impl<K: Hash + Eq> CycleDetector<K> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+
+    pub fn record(&mut self, state: K, step: usize, value: i64) -> Option<Cycle> {
+        match self.seen.get(&state) {
+            Some((start, start_value)) => Some(Cycle {
+                length: step - start,
+                delta: value - start_value,
+            }),
+            None => {
+                self.seen.insert(state, (step, value));
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod cycle;
+pub mod ocr;
+pub mod voxel;
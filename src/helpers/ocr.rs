@@ -0,0 +1,56 @@
+use crate::error::OcrError;
+
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+const LETTER_SPACING: usize = 5;
+
+const ALPHABET: [(char, [&str; LETTER_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters drawn in the standard advent of code 4x6 block font,
+/// where each letter is followed by a blank column. A glyph that is not
+/// recognised fails the whole read, so a partly read answer is never returned.
+pub fn read_letters(pixels: &[Vec<bool>]) -> Result<String, OcrError> {
+    let width = pixels.iter().map(|x| x.len()).min().unwrap_or_default();
+    if pixels.len() < LETTER_HEIGHT || width < LETTER_WIDTH {
+        return Err(OcrError::NoLetters);
+    }
+
+    (0..width)
+        .step_by(LETTER_SPACING)
+        .filter(|x| x + LETTER_WIDTH <= width)
+        .enumerate()
+        .map(|(i, x)| read_letter(pixels, x).ok_or(OcrError::UnknownGlyph(i)))
+        .collect()
+}
+
+fn read_letter(pixels: &[Vec<bool>], offset: usize) -> Option<char> {
+    ALPHABET
+        .iter()
+        .find(|(_, glyph)| {
+            glyph.iter().zip(pixels).all(|(glyph_row, row)| {
+                glyph_row
+                    .chars()
+                    .zip(&row[offset..offset + LETTER_WIDTH])
+                    .all(|(c, lit)| (c == '#') == *lit)
+            })
+        })
+        .map(|(c, _)| *c)
+}
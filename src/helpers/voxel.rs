@@ -0,0 +1,118 @@
+use std::{collections::HashSet, num::ParseIntError, ops::Add};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const FACES: [Point3; 6] = [
+        Point3::new(1, 0, 0),
+        Point3::new(-1, 0, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, -1, 0),
+        Point3::new(0, 0, 1),
+        Point3::new(0, 0, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point3> {
+        Self::FACES.into_iter().map(move |x| self + x)
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    pub fn within(self, min: Self, max: Self) -> bool {
+        self.min(min) == min && self.max(max) == max
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl TryFrom<&str> for Point3 {
+    type Error = ParseIntError;
+    fn try_from(s: &str) -> Result<Self, ParseIntError> {
+        let mut split = s.trim().splitn(3, ',');
+        let mut next = || split.next().unwrap_or_default().trim().parse::<i32>();
+        Ok(Self::new(next()?, next()?, next()?))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VoxelSet {
+    voxels: HashSet<Point3>,
+}
+
+impl FromIterator<Point3> for VoxelSet {
+    fn from_iter<I: IntoIterator<Item = Point3>>(iter: I) -> Self {
+        Self {
+            voxels: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl VoxelSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, point: Point3) -> bool {
+        self.voxels.insert(point)
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        self.voxels.contains(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Point3> {
+        self.voxels.iter()
+    }
+
+    /// The smallest and largest corners of the box containing every voxel.
+    pub fn bounds(&self) -> Option<(Point3, Point3)> {
+        let first = *self.voxels.iter().next()?;
+        Some(
+            self.voxels
+                .iter()
+                .fold((first, first), |(min, max), x| (min.min(*x), max.max(*x))),
+        )
+    }
+
+    /// Counts the faces of voxels in the set that are not shared with another voxel.
+    pub fn surface_area(&self) -> usize {
+        self.voxels
+            .iter()
+            .flat_map(|x| x.neighbours())
+            .filter(|x| !self.contains(x))
+            .count()
+    }
+}
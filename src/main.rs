@@ -1,43 +1,121 @@
+use std::process;
+
 use clap::{Arg, ArgAction, ColorChoice, Command};
 
-mod days;
+#[cfg(feature = "alloc-stats")]
+mod alloc_stats;
 mod error;
+mod helpers;
 mod prelude;
 mod utils;
-use days::*;
+mod years;
+use error::YearCommandError;
 use prelude::*;
+use utils::{default_year, run_all, run_compare, Verbosity};
+use years::*;
+
+fn main() {
+    // errors are printed with Display, the same way all reports a failed part, which
+    // matters for the parts all runs in child processes
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run() -> BoxResult<()> {
+    let years = generate_years()?;
+    let default_year = default_year();
 
-fn main() -> BoxResult<()> {
-    let commands = generate_days()?;
+    // a default year that isn't registered only fails the days run without a year
+    let default = years.iter().find(|x| x.get_name() == default_year);
+    let default_days = default.map_or_else(Vec::new, |x| x.get_days().to_vec());
 
     let args = vec![
         Arg::new("time taken")
             .short('t')
             .action(ArgAction::SetTrue)
-            .conflicts_with("quiet"),
-        Arg::new("quiet").short('q').action(ArgAction::SetTrue),
+            .conflicts_with("quiet")
+            .help("print the time taken to run the part"),
+        Arg::new("quiet")
+            .short('q')
+            .action(ArgAction::SetTrue)
+            .help("only print the answer"),
+        Arg::new("verbose")
+            .short('v')
+            .action(ArgAction::Count)
+            .conflicts_with("quiet")
+            .help("print parse stats and timings, twice to also print diagnostics"),
+        Arg::new("timeout")
+            .long("timeout")
+            .value_name("secs")
+            .value_parser(clap::value_parser!(u64))
+            .help("fail the part if it runs for longer than this"),
+        Arg::new("isolate")
+            .long("isolate")
+            .action(ArgAction::SetTrue)
+            .help("run the part in a child process, so a timeout does not end this one"),
+        Arg::new("memory")
+            .long("memory")
+            .value_name("MiB")
+            .value_parser(clap::value_parser!(u64))
+            .requires("isolate")
+            .help("limit the memory of the child process run with --isolate"),
         Arg::new("file")
             .short('f')
             .value_name("file")
-            .required(true),
+            .help("the input file, defaults to inputs/<year>/<day>.txt"),
     ];
 
     let matches = Command::new("adv")
         .color(ColorChoice::Always)
-        .about("Advent of code 2022 cli utility")
+        .about("Advent of code cli utility")
+        .after_help(format!(
+            "Days can be run without a year to use the default year, currently {} (set with ADV_YEAR)",
+            default_year
+        ))
         .args(args)
         .version("0.0.1")
         .arg_required_else_help(true)
-        .subcommands(commands.clone())
+        .subcommand(
+            Command::new("all")
+                .about("run every part of every year with its default input")
+                .arg(
+                    Arg::new("jobs")
+                        .short('j')
+                        .long("jobs")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("the number of parts to run at once, defaults to the number of cpus"),
+                ),
+        )
+        .subcommand(
+            Command::new("compare")
+                .about("run every variant of a part, check they agree and compare their timings")
+                .subcommands(years.clone())
+                .subcommands(default_days.clone())
+                .allow_external_subcommands(default.is_none())
+                .subcommand_required(true),
+        )
+        .subcommands(years.clone())
+        .subcommands(default_days)
+        .allow_external_subcommands(default.is_none())
         .subcommand_required(true)
         .get_matches();
 
+    Verbosity::from(&matches).init_logging();
+
     let subcommand = matches.subcommand().unwrap();
+    let default = default.ok_or(YearCommandError::YearNotFound(default_year));
+
+    match subcommand.0 {
+        "all" => return run_all(&years, &matches),
+        "compare" => return run_compare(&years, &matches),
+        _ => {}
+    }
 
-    for d in commands {
-        if d.get_name() == subcommand.0 {
-            d.run(matches.to_owned())?;
-        }
+    match years.iter().find(|x| x.get_name() == subcommand.0) {
+        Some(year) => year.run(matches.to_owned())?,
+        None => default?.run(matches.to_owned())?,
     }
     Ok(())
 }
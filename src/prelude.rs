@@ -1,9 +1,13 @@
 use std::error::*;
 pub type BoxResult<T> = std::result::Result<T, Box<dyn Error>>;
-pub use crate::utils::{DayCommand, DayCommandBuilder, FileReader, PartBuilder};
+pub use crate::utils::{
+    CommandResponse, DayCommand, DayCommandBuilder, FileReader, Input, PartArg, PartArgs,
+    PartBuilder, Progress, YearCommand, YearCommandBuilder,
+};
 
 pub use std::fs::File;
 pub use std::io::{BufRead, BufReader};
 pub use std::path::Path;
 
 pub use clap::ArgMatches;
+pub use tracing::{debug, info};
@@ -1,33 +1,112 @@
 use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    env,
     fmt::Display,
+    fs,
     ops::Deref,
-    rc::Rc,
-    time::{Duration, Instant}, error::Error, io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::{self, Child, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant}, io::{self, IsTerminal, Read},
 };
 
 use crate::{
-    error::{DayCommandBuilderError, DayCommandError},
+    error::{
+        AllCommandError, CompareError, DayCommandBuilderError, DayCommandError, PartArgError, PartRunError,
+        YearCommandBuilderError, YearCommandError,
+    },
     prelude::*,
 };
-use clap::Command;
-use indicatif::ProgressBar;
+use clap::{builder::PossibleValuesParser, Arg, ArgAction, Command};
+use indicatif::{ProgressBar, ProgressStyle};
+use tracing::{info, info_span, Level};
+use tracing_subscriber::fmt::format::FmtSpan;
 
-pub struct FileReader {
-    reader: BufReader<File>,
-    buf: String,
+/// The whole input file, read once and handed out as borrowed lines, groups or bytes.
+pub struct Input {
+    text: String,
 }
 
-impl TryFrom<ArgMatches> for FileReader {
+impl TryFrom<PartArgs> for Input {
     type Error = io::Error;
-    fn try_from(args: ArgMatches) -> Result<Self, io::Error> {
-        let path = args
-            .get_one::<String>("file").unwrap();
-        let path = Path::new(path);
+    fn try_from(args: PartArgs) -> Result<Self, io::Error> {
+        let text = fs::read_to_string(args.file())?;
+        info!(file = %args.file().display(), bytes = text.len(), "reading input");
+        Ok(Self { text })
+    }
+}
+
+impl Input {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    /// Returns every line trimmed of whitespace, including blank ones.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines().map(|x| x.trim())
+    }
+
+    /// Returns the runs of lines separated by blank lines, each trimmed of whitespace.
+    pub fn groups(&self) -> Groups<'_> {
+        Groups {
+            text: &self.text,
+            pos: 0,
+        }
+    }
+}
+
+pub struct Groups<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.pos..];
+        let start = self.pos + rest.len() - rest.trim_start().len();
+        if start == self.text.len() {
+            return None;
+        }
+
+        let mut end = start;
+        for line in self.text[start..].split_inclusive('\n') {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+
+        self.pos = end;
+        Some(self.text[start..end].trim_end())
+    }
+}
 
-        let f = File::open(path)?;
+/// Owned lines of the input, for parts that parse from a `String`.
+pub struct FileReader {
+    input: Input,
+    pos: usize,
+}
+
+impl TryFrom<PartArgs> for FileReader {
+    type Error = io::Error;
+    fn try_from(args: PartArgs) -> Result<Self, io::Error> {
         Ok(Self {
-            reader: BufReader::new(f),
-            buf: String::new(),
+            input: Input::try_from(args)?,
+            pos: 0,
         })
     }
 }
@@ -36,26 +115,323 @@ impl Iterator for FileReader {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.buf.clear();
-        let res = self.reader.read_line(&mut self.buf);
-
-        if res.is_err() {
-            eprintln!("error reading line, got err: {}", res.unwrap());
+        let rest = &self.input.text()[self.pos..];
+        if rest.is_empty() {
             return None;
         }
 
-        if res.unwrap() == 0 {
-            return None;
+        let line = match rest.find('\n') {
+            Some(end) => &rest[..end],
+            None => rest,
+        };
+        self.pos += (line.len() + 1).min(rest.len());
+
+        Some(line.trim().to_string())
+    }
+}
+
+const DEFAULT_YEAR: &str = "2022";
+
+/// Set on the child process a part is run in with `--isolate`.
+const ISOLATED_ENV: &str = "ADV_ISOLATED";
+
+/// The exit code used when a part runs past `--timeout`, the same as coreutils' timeout.
+const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// The year used when a day is run without naming one, set with `ADV_YEAR`.
+pub fn default_year() -> String {
+    env::var("ADV_YEAR").unwrap_or_else(|_| DEFAULT_YEAR.to_string())
+}
+
+/// Returns the names of the chain of subcommands that were matched, outermost first.
+pub fn subcommand_path(args: &ArgMatches) -> Vec<&str> {
+    let mut path = Vec::new();
+    let mut args = args;
+    while let Some((name, sub)) = args.subcommand() {
+        path.push(name);
+        args = sub;
+    }
+    path
+}
+
+/// Returns the file given with `-f`, or `inputs/<year>/<day>.txt` for the day being run.
+pub fn input_path(args: &ArgMatches) -> PathBuf {
+    if let Some(path) = args.get_one::<String>("file") {
+        return PathBuf::from(path);
+    }
+
+    let path = subcommand_path(args);
+    match path[..] {
+        [year, day, ..] if year.chars().all(|x| x.is_ascii_digit()) => default_input(year, day),
+        [day, ..] => default_input(&default_year(), day),
+        [] => default_input(&default_year(), ""),
+    }
+}
+
+fn default_input(year: &str, day: &str) -> PathBuf {
+    Path::new("inputs").join(year).join(format!("{}.txt", day))
+}
+
+/// Returns the matches of the part subcommand, which hold the args the part declared.
+fn part_matches(args: &ArgMatches) -> &ArgMatches {
+    match args.subcommand() {
+        Some((_, sub)) => part_matches(sub),
+        None => args,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ArgValue {
+    Flag(bool),
+    Int(i64),
+}
+
+pub trait FromArgValue: Sized {
+    fn from_arg_value(value: &ArgValue) -> Option<Self>;
+}
+
+impl FromArgValue for bool {
+    fn from_arg_value(value: &ArgValue) -> Option<Self> {
+        match value {
+            ArgValue::Flag(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+impl FromArgValue for i64 {
+    fn from_arg_value(value: &ArgValue) -> Option<Self> {
+        match value {
+            ArgValue::Int(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+impl FromArgValue for usize {
+    fn from_arg_value(value: &ArgValue) -> Option<Self> {
+        match value {
+            ArgValue::Int(x) => usize::try_from(*x).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// An argument a part accepts, whose default also decides the type of its value.
+#[derive(Debug, Clone)]
+pub struct PartArg {
+    name: &'static str,
+    short: Option<char>,
+    help: &'static str,
+    default: ArgValue,
+}
+
+impl PartArg {
+    fn new(name: &'static str, default: ArgValue) -> Self {
+        PartArg {
+            name,
+            short: None,
+            help: "",
+            default,
+        }
+    }
+
+    pub fn flag(name: &'static str) -> Self {
+        Self::new(name, ArgValue::Flag(false))
+    }
+
+    pub fn int(name: &'static str, default: i64) -> Self {
+        Self::new(name, ArgValue::Int(default))
+    }
+
+    pub fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    pub fn help(mut self, help: &'static str) -> Self {
+        self.help = help;
+        self
+    }
+
+    fn value(&self, args: &ArgMatches) -> ArgValue {
+        match &self.default {
+            ArgValue::Flag(_) => ArgValue::Flag(args.get_flag(self.name)),
+            ArgValue::Int(x) => ArgValue::Int(args.get_one::<i64>(self.name).copied().unwrap_or(*x)),
+        }
+    }
+}
+
+impl From<PartArg> for Arg {
+    fn from(part_arg: PartArg) -> Self {
+        let mut arg = Arg::new(part_arg.name)
+            .long(part_arg.name.replace(' ', "-"))
+            .help(part_arg.help);
+
+        if let Some(short) = part_arg.short {
+            arg = arg.short(short);
+        }
+
+        match part_arg.default {
+            ArgValue::Flag(_) => arg.action(ArgAction::SetTrue),
+            ArgValue::Int(x) => arg
+                .value_parser(clap::value_parser!(i64))
+                .allow_negative_numbers(true)
+                .default_value(x.to_string()),
         }
+    }
+}
+
+/// The input file and the values of the args a part declared, as handed to the part.
+pub struct PartArgs {
+    file: PathBuf,
+    values: HashMap<&'static str, ArgValue>,
+    progress: Progress,
+}
+
+impl PartArgs {
+    fn new(part: &Part, args: &ArgMatches, file: PathBuf, progress: Progress) -> Self {
+        let matches = part_matches(args);
+        PartArgs {
+            file,
+            values: part.args.iter().map(|x| (x.name, x.value(matches))).collect(),
+            progress,
+        }
+    }
+
+    /// The args a part gets when it is run without a command line of its own.
+    fn defaults(part: &Part, file: PathBuf, progress: Progress) -> Self {
+        PartArgs {
+            file,
+            values: part.args.iter().map(|x| (x.name, x.default.clone())).collect(),
+            progress,
+        }
+    }
 
-        Some(self.buf.clone().trim().to_string())
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    pub fn progress(&self) -> Progress {
+        self.progress.clone()
+    }
+
+    pub fn get<T: FromArgValue>(&self, name: &str) -> Result<T, PartArgError> {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| PartArgError::NotDeclared(name.to_string()))?;
+
+        T::from_arg_value(value)
+            .ok_or_else(|| PartArgError::WrongType(name.to_string(), value.to_owned()))
+    }
+}
+
+/// A handle a part can report its progress through, drawn on the spinner while it runs.
+/// It can be cloned and sent to other threads.
+#[derive(Clone)]
+pub struct Progress {
+    bar: ProgressBar,
+}
+
+impl Progress {
+    fn new(verbosity: Verbosity) -> Self {
+        // log lines would be drawn over by the bar, so it only shows by default
+        let bar = match verbosity {
+            Verbosity::Normal if io::stderr().is_terminal() => ProgressBar::new_spinner(),
+            _ => ProgressBar::hidden(),
+        };
+        bar.set_style(
+            ProgressStyle::with_template("{spinner} running command [{elapsed}] {msg}").unwrap(),
+        );
+        bar.enable_steady_tick(Duration::from_millis(100));
+        Progress { bar }
+    }
+
+    fn hidden() -> Self {
+        Progress {
+            bar: ProgressBar::hidden(),
+        }
+    }
+
+    /// Turns the spinner into a bar with an eta, for parts that know how many steps they take.
+    pub fn set_total(&self, steps: u64) {
+        self.bar.set_length(steps);
+        self.bar.set_style(
+            ProgressStyle::with_template(
+                "{spinner} running command [{elapsed}] {wide_bar} {pos}/{len} eta {eta} {msg}",
+            )
+            .unwrap(),
+        );
+    }
+
+    pub fn inc(&self, steps: u64) {
+        self.bar.inc(steps);
+    }
+
+    pub fn set_best<T: Display>(&self, best: T) {
+        self.bar.set_message(format!("best so far {}", best));
+    }
+
+    fn finish(&self) {
+        self.bar.finish_and_clear();
+    }
+
+    fn suspend<F: FnOnce()>(&self, f: F) {
+        self.bar.suspend(f);
     }
 }
 
 pub struct CommandResponse<T> {
     value: T,
     pretty_print: String,
-    info: String,
+}
+
+/// How much is printed besides the answer, from `-q` up to `-vv`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+    Debug,
+}
+
+impl From<&ArgMatches> for Verbosity {
+    fn from(args: &ArgMatches) -> Self {
+        if args.get_flag("quiet") {
+            return Verbosity::Quiet;
+        }
+        match args.get_count("verbose") {
+            0 => Verbosity::Normal,
+            1 => Verbosity::Verbose,
+            _ => Verbosity::Debug,
+        }
+    }
+}
+
+impl Verbosity {
+    /// Sends log events to stderr, with parse stats and span timings from `-v`
+    /// and the diagnostics parts log from `-vv`.
+    pub fn init_logging(&self) {
+        let level = match self {
+            Verbosity::Quiet => return,
+            Verbosity::Normal => Level::WARN,
+            Verbosity::Verbose => Level::INFO,
+            Verbosity::Debug => Level::DEBUG,
+        };
+
+        tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_span_events(match self {
+                Verbosity::Normal => FmtSpan::NONE,
+                _ => FmtSpan::CLOSE,
+            })
+            .with_target(false)
+            // `without_time` would also drop the busy and idle times of closed spans
+            .with_timer(())
+            .with_writer(io::stderr)
+            .init();
+    }
 }
 
 #[derive(Clone)]
@@ -65,12 +441,35 @@ pub struct DayCommand {
     parts: Vec<Part>,
 }
 
+type PartFunc = Arc<dyn Fn(PartArgs) -> BoxResult<CommandResponse<String>> + Send + Sync>;
+
 #[derive(Clone)]
 pub struct Part {
     name: &'static str,
     short_flag: char,
-    func: Rc<dyn Fn(ArgMatches) -> BoxResult<()>>,
+    variants: Vec<Variant>,
     about: &'static str,
+    args: Vec<PartArg>,
+}
+
+/// One implementation of a part, so a naive and an optimized one can be kept side by side.
+#[derive(Clone)]
+pub struct Variant {
+    name: &'static str,
+    func: PartFunc,
+}
+
+#[derive(Clone)]
+pub struct YearCommand {
+    name: &'static str,
+    about: &'static str,
+    days: Vec<DayCommand>,
+}
+
+pub struct YearCommandBuilder {
+    name: Option<&'static str>,
+    about: Option<&'static str>,
+    days: Vec<DayCommand>,
 }
 
 pub struct DayCommandBuilder {
@@ -82,8 +481,9 @@ pub struct DayCommandBuilder {
 pub struct PartBuilder {
     name: Option<&'static str>,
     short_flag: Option<char>,
-    func: Rc<dyn Fn(ArgMatches) -> Result<(), Box<dyn Error>>>,
+    variants: Vec<Variant>,
     about: Option<&'static str>,
+    args: Vec<PartArg>,
 }
 
 impl<T:Display> Display for CommandResponse<T> {
@@ -92,13 +492,60 @@ impl<T:Display> Display for CommandResponse<T> {
     }
 }
 
-impl<T> CommandResponse<T> {}
+impl<T: Display> CommandResponse<T> {
+    pub fn new(value: T, pretty_print: impl Into<String>) -> Self {
+        CommandResponse {
+            value,
+            pretty_print: pretty_print.into(),
+        }
+    }
+
+    fn into_string(self) -> CommandResponse<String> {
+        CommandResponse {
+            value: self.value.to_string(),
+            pretty_print: self.pretty_print,
+        }
+    }
+}
+
+impl<T> CommandResponse<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
 
 impl From<Part> for Command {
     fn from(part: Part) -> Self {
-        Command::new(part.name)
+        let variants = part.variants.iter().map(|x| x.name).collect::<Vec<_>>();
+        let command = Command::new(part.name)
             .about(part.about)
             .short_flag(part.short_flag)
+            .args(part.args);
+
+        match variants.len() {
+            1 => command,
+            _ => command.arg(
+                Arg::new("variant")
+                    .long("variant")
+                    .value_name("name")
+                    .value_parser(PossibleValuesParser::new(variants))
+                    .help("the variant of the part to run, defaults to the first one it declares"),
+            ),
+        }
+    }
+}
+
+impl Part {
+    /// Returns the named variant, or the first one declared when no name is given.
+    fn variant(&self, name: Option<&str>) -> Result<&Variant, DayCommandError> {
+        match name {
+            Some(name) => self
+                .variants
+                .iter()
+                .find(|x| x.name == name)
+                .ok_or(DayCommandError::VariantNotFound(name.to_string())),
+            None => Ok(&self.variants[0]),
+        }
     }
 }
 
@@ -111,9 +558,47 @@ impl From<DayCommand> for Command {
     }
 }
 
+impl From<YearCommand> for Command {
+    fn from(year: YearCommand) -> Self {
+        Command::new(year.name)
+            .about(year.about)
+            .subcommands(year.days)
+            .subcommand_required(true)
+    }
+}
+
+impl YearCommand {
+    pub fn run(&self, args: ArgMatches) -> BoxResult<()> {
+        let path = subcommand_path(&args);
+        let name = match path[..] {
+            [year, day, ..] if year == self.name => day,
+            [day, ..] => day,
+            [] => "",
+        };
+
+        let day = self
+            .days
+            .iter()
+            .find(|x| x.name == name)
+            .ok_or(YearCommandError::DayNotFound(name.to_string()))?;
+
+        let _span = info_span!("year", year = self.name).entered();
+        day.run(args)
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name
+    }
+
+    pub fn get_days(&self) -> &[DayCommand] {
+        &self.days
+    }
+}
+
 impl DayCommand {
     pub fn run(&self, args: ArgMatches) -> BoxResult<()> {
-        let (name, _) = args.subcommand().unwrap().1.subcommand().unwrap();
+        let path = subcommand_path(&args);
+        let name = path.last().copied().unwrap_or_default();
 
         let part = self
             .parts
@@ -121,11 +606,32 @@ impl DayCommand {
             .find(|x| x.name == name)
             .ok_or(DayCommandError::CommandPartNotFound(name.to_string()))?;
 
-        let ids: Vec<&str> = args.ids().into_iter().map(|x| x.as_str()).collect();
+        let verbosity = Verbosity::from(&args);
+        let _span = info_span!("part", day = self.name, part = part.name).entered();
+
+        let timeout = args
+            .get_one::<u64>("timeout")
+            .map(|x| Duration::from_secs(*x));
 
-        let mut func: Box<dyn Fn(ArgMatches) -> BoxResult<()>>;
+        if env::var_os(ISOLATED_ENV).is_some() {
+            if let Some(mb) = args.get_one::<u64>("memory") {
+                limit_memory(*mb)?;
+            }
+        } else if args.get_flag("isolate") {
+            return run_isolated(timeout);
+        }
+
+        let ids: Vec<&str> = args.ids().map(|x| x.as_str()).collect();
 
-        func = Box::new(Rc::deref(&part.func));
+        let mut func: Box<dyn Fn(PartArgs) -> BoxResult<CommandResponse<String>>>;
+
+        // only parts with more than one variant declare --variant
+        let variant = part_matches(&args)
+            .try_get_one::<String>("variant")
+            .ok()
+            .flatten();
+        let variant = part.variant(variant.map(|x| x.as_str()))?;
+        func = Box::new(Arc::deref(&variant.func));
 
         for i in ids {
             if args.try_get_one::<bool>(i).is_err() {
@@ -140,23 +646,42 @@ impl DayCommand {
             }
         }
 
-        let spin = ProgressBar::new_spinner();
-        spin.enable_steady_tick(Duration::from_millis(100));
-        spin.set_message("running command");
-        func(args)?;
-        spin.finish_and_clear();
+        if verbosity >= Verbosity::Verbose && !args.get_flag("time taken") {
+            func = Box::new(self.time_wrapper(func));
+        }
+
+        let progress = Progress::new(verbosity);
+        let part_args = PartArgs::new(part, &args, input_path(&args), progress.clone());
+
+        // the isolated child leaves the timeout to its parent
+        let watchdog = match env::var_os(ISOLATED_ENV) {
+            None => timeout.map(|x| watchdog(x, progress.clone())),
+            Some(_) => None,
+        };
+        let response = catch_panic(|| func(part_args));
+        drop(watchdog);
+        progress.finish();
+
+        match verbosity {
+            Verbosity::Quiet => println!("{}", response?.value()),
+            _ => println!("{}", response?),
+        }
         Ok(())
     }
 
-    fn time_wrapper<F: Fn(ArgMatches) -> BoxResult<()>>(
+    fn time_wrapper<F: Fn(PartArgs) -> BoxResult<CommandResponse<String>>>(
         &self,
         f: F,
-    ) -> impl Fn(ArgMatches) -> BoxResult<()> {
-        move |args: ArgMatches| {
-            let time = Instant::now();
-            let res = f(args);
-            let elapsed = time.elapsed().as_micros();
-            println!("Time taken to execute command was {} microseconds", elapsed);
+    ) -> impl Fn(PartArgs) -> BoxResult<CommandResponse<String>> {
+        move |args: PartArgs| {
+            let (res, elapsed, stats) = measure(|| f(args));
+            println!(
+                "Time taken to execute command was {} microseconds",
+                elapsed.as_micros()
+            );
+            if let Some(stats) = stats {
+                println!("The command made {}", stats);
+            }
             res
         }
     }
@@ -166,13 +691,414 @@ impl DayCommand {
     }
 }
 
+/// Runs every part of every year on a pool of `--jobs` threads, each with its default
+/// input and args, printing the results in the order the parts were registered.
+///
+/// With `--timeout` or `--isolate` each part is run in its own child process instead,
+/// so a part that runs too long only fails its own row.
+pub fn run_all(years: &[YearCommand], args: &ArgMatches) -> BoxResult<()> {
+    let verbosity = Verbosity::from(args);
+    let timed = args.get_flag("time taken");
+    let timeout = args
+        .get_one::<u64>("timeout")
+        .map(|x| Duration::from_secs(*x));
+    let isolate = timeout.is_some() || args.get_flag("isolate");
+    let workers = match part_matches(args).get_one::<usize>("jobs") {
+        Some(x) => *x,
+        None => thread::available_parallelism().map_or(1, |x| x.get()),
+    };
+
+    let jobs = years
+        .iter()
+        .flat_map(|year| {
+            year.days.iter().flat_map(move |day| {
+                day.parts
+                    .iter()
+                    .map(move |part| (year.name, day.name, part))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let progress = Progress::new(verbosity);
+    progress.set_total(jobs.len() as u64);
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    let mut failed = 0;
+
+    thread::scope(|s| {
+        for _ in 0..workers.max(1) {
+            let (tx, jobs, next) = (tx.clone(), &jobs, &next);
+            s.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((year, day, part)) = jobs.get(i) else {
+                    break;
+                };
+
+                let _span = info_span!("part", year, day, part = part.name).entered();
+
+                let (response, elapsed, stats) = match isolate {
+                    true => {
+                        let time = Instant::now();
+                        let response = run_child(year, day, part.name, args, timeout);
+                        (response, time.elapsed(), None)
+                    }
+                    false => run_default(year, day, part, verbosity),
+                };
+                let response = response.map_err(|x| x.to_string());
+
+                if tx.send((i, response, elapsed, stats)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // results arrive as parts finish, so they wait here until every part before them is printed
+        let mut results = Vec::new();
+        results.resize_with(jobs.len(), || None);
+        let mut printed = 0;
+
+        for (i, response, elapsed, stats) in rx {
+            progress.inc(1);
+            results[i] = Some((response, elapsed, stats));
+
+            while let Some(Some((response, elapsed, stats))) =
+                results.get_mut(printed).map(Option::take)
+            {
+                let (year, day, part) = jobs[printed];
+                let line = match response {
+                    Ok(x) => x,
+                    Err(e) => {
+                        failed += 1;
+                        format!("failed with {}", e)
+                    }
+                };
+
+                progress.suspend(|| match timed {
+                    true => println!(
+                        "{} {} {}: {} ({} microseconds{})",
+                        year,
+                        day,
+                        part.name,
+                        line,
+                        elapsed.as_micros(),
+                        stats.map_or(String::new(), |x| format!(", {}", x))
+                    ),
+                    false => println!("{} {} {}: {}", year, day, part.name, line),
+                });
+                printed += 1;
+            }
+        }
+    });
+    progress.finish();
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(AllCommandError::PartsFailed(failed, jobs.len()).into()),
+    }
+}
+
+/// Runs a part of `all` on this thread with its default input and args, returning the
+/// line to print for it.
+fn run_default(
+    year: &str,
+    day: &str,
+    part: &Part,
+    verbosity: Verbosity,
+) -> (BoxResult<String>, Duration, Option<String>) {
+    let args = PartArgs::defaults(part, default_input(year, day), Progress::hidden());
+    let (response, elapsed, stats) = measure(|| catch_panic(|| (part.variants[0].func)(args)));
+
+    let response = response.map(|x| match verbosity {
+        Verbosity::Quiet => x.value().to_string(),
+        _ => x.to_string(),
+    });
+    (response, elapsed, stats)
+}
+
+/// Runs every variant of a part on the same input one after another, checking they
+/// agree and reporting how long each took against the fastest.
+pub fn run_compare(years: &[YearCommand], args: &ArgMatches) -> BoxResult<()> {
+    let verbosity = Verbosity::from(args);
+    let path = subcommand_path(args);
+
+    // the year can be left out to compare a part of the default year
+    let (year, rest) = match years.iter().find(|x| path.get(1) == Some(&x.name)) {
+        Some(year) => (year, &path[2..]),
+        None => {
+            let name = default_year();
+            let year = years
+                .iter()
+                .find(|x| x.name == name)
+                .ok_or(YearCommandError::YearNotFound(name))?;
+            (year, &path[1..])
+        }
+    };
+    let day_name = rest.first().copied().unwrap_or_default();
+    let part_name = rest.get(1).copied().unwrap_or_default();
+
+    let day = year
+        .days
+        .iter()
+        .find(|x| x.name == day_name)
+        .ok_or(YearCommandError::DayNotFound(day_name.to_string()))?;
+
+    let part = day
+        .parts
+        .iter()
+        .find(|x| x.name == part_name)
+        .ok_or(DayCommandError::CommandPartNotFound(part_name.to_string()))?;
+
+    if let Ok(Some(variant)) = part_matches(args).try_get_one::<String>("variant") {
+        return Err(CompareError::VariantGiven(variant.to_string()).into());
+    }
+
+    let file = match args.get_one::<String>("file") {
+        Some(x) => PathBuf::from(x),
+        None => default_input(year.name, day.name),
+    };
+
+    let _span = info_span!("part", year = year.name, day = day.name, part = part.name).entered();
+
+    let mut results = Vec::new();
+    for variant in &part.variants {
+        let part_args = PartArgs::new(part, args, file.clone(), Progress::hidden());
+        let (response, elapsed, stats) = measure(|| catch_panic(|| (variant.func)(part_args)));
+        results.push((variant.name, response?, elapsed, stats));
+    }
+
+    let (first, answer, _, _) = &results[0];
+    for (name, response, _, _) in &results[1..] {
+        if response.value() != answer.value() {
+            return Err(CompareError::Disagree(
+                first,
+                answer.value().to_owned(),
+                name,
+                response.value().to_owned(),
+            )
+            .into());
+        }
+    }
+
+    if verbosity == Verbosity::Quiet {
+        println!("{}", answer.value());
+        return Ok(());
+    }
+    println!("{}", answer);
+
+    let fastest = results
+        .iter()
+        .map(|x| x.2)
+        .min()
+        .unwrap_or_default()
+        .max(Duration::from_nanos(1));
+
+    for (name, _, elapsed, stats) in &results {
+        println!(
+            "{}: {} microseconds, {:.2}x the fastest{}",
+            name,
+            elapsed.as_micros(),
+            elapsed.as_secs_f64() / fastest.as_secs_f64(),
+            stats.as_ref().map_or(String::new(), |x| format!(", {}", x))
+        );
+    }
+    Ok(())
+}
+
+/// Runs `f`, timing it and, when built with the `alloc-stats` feature, counting the
+/// allocations it makes.
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<String>) {
+    let time = Instant::now();
+    #[cfg(feature = "alloc-stats")]
+    let (res, stats) = crate::alloc_stats::measure(f);
+    #[cfg(feature = "alloc-stats")]
+    let stats = Some(stats.to_string());
+    #[cfg(not(feature = "alloc-stats"))]
+    let (res, stats) = (f(), None);
+    (res, time.elapsed(), stats)
+}
+
+/// Ends the process once the timeout has passed, unless the returned sender is dropped first.
+fn watchdog(timeout: Duration, progress: Progress) -> mpsc::Sender<()> {
+    let (done, rx) = mpsc::channel();
+    thread::spawn(move || {
+        if let Err(RecvTimeoutError::Timeout) = rx.recv_timeout(timeout) {
+            progress.finish();
+            eprintln!("Error: {}", PartRunError::TimedOut(timeout));
+            process::exit(TIMEOUT_EXIT_CODE);
+        }
+    });
+    done
+}
+
+thread_local! {
+    /// Set while this thread runs a part whose panics are caught.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last caught panic on this thread happened.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs a part, turning a panic in it into an error. The panic is only reported through
+/// that error, so the default hook's message doesn't end up among the results.
+fn catch_panic(
+    f: impl FnOnce() -> BoxResult<CommandResponse<String>>,
+) -> BoxResult<CommandResponse<String>> {
+    // the hook is shared by every thread, so panics outside a part still get the default one
+    static QUIET_HOOK: Once = Once::new();
+    QUIET_HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CATCHING.with(Cell::get) {
+            true => {
+                PANIC_LOCATION.with(|x| *x.borrow_mut() = info.location().map(|x| x.to_string()))
+            }
+            false => default(info),
+        }));
+    });
+
+    CATCHING.with(|x| x.set(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|x| x.set(false));
+
+    res.unwrap_or_else(|x| {
+        let message = match PANIC_LOCATION.with(|x| x.borrow_mut().take()) {
+            Some(location) => format!("{} at {}", panic_message(x), location),
+            None => panic_message(x),
+        };
+        Err(PartRunError::Panicked(message).into())
+    })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(x) => *x,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(x) => x.to_string(),
+            Err(_) => "a non string payload".to_string(),
+        },
+    }
+}
+
+/// Waits for the child to exit, killing it and returning `None` once the timeout has passed.
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Option<Duration>,
+) -> io::Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(Some);
+    };
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Runs the same command again in a child process, killing it once the timeout has passed.
+fn run_isolated(timeout: Option<Duration>) -> BoxResult<()> {
+    let mut child = process::Command::new(env::current_exe()?)
+        .args(env::args_os().skip(1))
+        .env(ISOLATED_ENV, "1")
+        .spawn()?;
+
+    let Some(status) = wait_with_timeout(&mut child, timeout)? else {
+        // exit the same way the watchdog does when the part is not isolated
+        eprintln!(
+            "Error: {}",
+            PartRunError::TimedOut(timeout.unwrap_or_default())
+        );
+        process::exit(TIMEOUT_EXIT_CODE);
+    };
+
+    match status.success() {
+        true => Ok(()),
+        false => Err(PartRunError::ChildFailed(status).into()),
+    }
+}
+
+/// Runs a part of `all` in a child process with its default input, returning the line
+/// it printed or the error it exited with.
+fn run_child(
+    year: &str,
+    day: &str,
+    part: &str,
+    args: &ArgMatches,
+    timeout: Option<Duration>,
+) -> BoxResult<String> {
+    let mut command = process::Command::new(env::current_exe()?);
+    if Verbosity::from(args) == Verbosity::Quiet {
+        command.arg("-q");
+    }
+    if let Some(mb) = args.get_one::<u64>("memory") {
+        command.args(["--isolate", "--memory", &mb.to_string()]);
+    }
+
+    let mut child = command
+        .args([year, day, part])
+        .env(ISOLATED_ENV, "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // the pipes are drained while waiting, so a part printing a lot can't block on them
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let Some(status) = wait_with_timeout(&mut child, timeout)? else {
+        return Err(PartRunError::TimedOut(timeout.unwrap_or_default()).into());
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if status.success() {
+        return Ok(stdout.trim().to_string());
+    }
+    match stderr.lines().rev().find(|x| !x.trim().is_empty()) {
+        Some(line) => Err(line.trim_start_matches("Error: ").into()),
+        None => Err(PartRunError::ChildFailed(status).into()),
+    }
+}
+
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut text);
+        }
+        text
+    })
+}
+
+#[cfg(unix)]
+fn limit_memory(mb: u64) -> BoxResult<()> {
+    let bytes = mb * 1024 * 1024;
+    rlimit::setrlimit(rlimit::Resource::AS, bytes, bytes)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_: u64) -> BoxResult<()> {
+    Err(PartRunError::MemoryLimitUnsupported.into())
+}
+
 impl PartBuilder {
     pub fn new() -> Self {
         PartBuilder {
             name: None,
             short_flag: None,
-            func: Rc::new(|_| Ok(())),
+            variants: Vec::new(),
             about: None,
+            args: Vec::new(),
         }
     }
 
@@ -191,8 +1117,28 @@ impl PartBuilder {
         self
     }
 
-    pub fn func(&mut self, func: impl Fn(ArgMatches) -> BoxResult<()> + 'static) -> &mut Self {
-        self.func = Rc::new(func);
+    pub fn arg(&mut self, arg: PartArg) -> &mut Self {
+        self.args.push(arg);
+        self
+    }
+
+    pub fn func<T: Display>(
+        &mut self,
+        func: impl Fn(PartArgs) -> BoxResult<CommandResponse<T>> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.variant("default", func)
+    }
+
+    /// Adds a named implementation of the part, the first one added is run by default.
+    pub fn variant<T: Display>(
+        &mut self,
+        name: &'static str,
+        func: impl Fn(PartArgs) -> BoxResult<CommandResponse<T>> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.variants.push(Variant {
+            name,
+            func: Arc::new(move |args| Ok(func(args)?.into_string())),
+        });
         self
     }
 
@@ -200,13 +1146,18 @@ impl PartBuilder {
         let name = self.name.ok_or(DayCommandBuilderError::NameNotFound)?;
         let about = self.about.unwrap_or_default();
         let short_flag = self.short_flag.unwrap_or(name.chars().last().unwrap());
-        let func = Rc::clone(&self.func);
+        let variants = match self.variants.len() {
+            0 => Err(DayCommandBuilderError::FuncNotSpecified),
+            _ => Ok(self.variants.to_owned()),
+        }?;
+        let args = self.args.to_owned();
 
         Ok(Part {
             name,
             short_flag,
-            func,
+            variants,
             about,
+            args,
         })
     }
 }
@@ -246,3 +1197,88 @@ impl DayCommandBuilder {
         Ok(DayCommand { name, about, parts })
     }
 }
+
+impl YearCommandBuilder {
+    pub fn new() -> Self {
+        YearCommandBuilder {
+            name: None,
+            about: None,
+            days: Vec::new(),
+        }
+    }
+
+    pub fn name(&mut self, name: &'static str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn about(&mut self, about: &'static str) -> &mut Self {
+        self.about = Some(about);
+        self
+    }
+
+    pub fn days(&mut self, days: &mut Vec<DayCommand>) -> &mut Self {
+        self.days.append(days);
+        self
+    }
+
+    pub fn build(&self) -> BoxResult<YearCommand> {
+        let name = self.name.ok_or(YearCommandBuilderError::NameNotFound)?;
+        let about = self.about.unwrap_or_default();
+        let days = match self.days.len() {
+            0 => Err(YearCommandBuilderError::DaysNotFound),
+            _ => Ok(self.days.to_owned()),
+        }?;
+
+        Ok(YearCommand { name, about, days })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(file: PathBuf) -> PartArgs {
+        PartArgs {
+            file,
+            values: HashMap::new(),
+            progress: Progress::hidden(),
+        }
+    }
+
+    /// Times reading a large day 1 style input with `FileReader` and with `Input`.
+    /// Run it with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_file_reader_against_input() -> BoxResult<()> {
+        let file = env::temp_dir().join(format!("adv_bench_{}.txt", process::id()));
+        let text = (0..2_000_000u64)
+            .map(|i| match i % 8 {
+                7 => String::new(),
+                _ => (i * 7919 % 100_000).to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&file, text)?;
+
+        let time = Instant::now();
+        let owned = FileReader::try_from(args(file.clone()))?.collect::<Vec<_>>();
+        let file_reader = time.elapsed();
+
+        let time = Instant::now();
+        let input = Input::try_from(args(file.clone()))?;
+        let borrowed = input.lines().collect::<Vec<_>>();
+        let input_lines = time.elapsed();
+
+        fs::remove_file(&file)?;
+        assert_eq!(owned, borrowed);
+
+        println!(
+            "{} lines: FileReader took {} microseconds, Input took {} microseconds",
+            owned.len(),
+            file_reader.as_micros(),
+            input_lines.as_micros()
+        );
+        Ok(())
+    }
+}
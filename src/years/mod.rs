@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+mod y2022;
+
+pub fn generate_years() -> BoxResult<Vec<YearCommand>> {
+    let years = vec![y2022::year_2022()?];
+    Ok(years)
+}
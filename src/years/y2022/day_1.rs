@@ -8,14 +8,12 @@ pub struct Calories {
     total: u32,
 }
 
-impl TryFrom<String> for Calories {
+impl TryFrom<&str> for Calories {
     type Error = ParseIntError;
-    fn try_from(s: String) -> Result<Self, ParseIntError> {
-        let s = s.trim();
-        let splits = s.split(' ').collect::<Vec<_>>();
+    fn try_from(s: &str) -> Result<Self, ParseIntError> {
         let mut total = 0;
 
-        for i in splits {
+        for i in s.split_whitespace() {
             total += i.parse::<u32>()?;
         }
 
@@ -57,6 +55,11 @@ pub fn day_1() -> BoxResult<DayCommand> {
             .name("part 2")
             .about("part 2 of the problem")
             .short_flag('2')
+            .arg(
+                PartArg::int("top", 3)
+                    .short('n')
+                    .help("the number of elves carrying the most calories to total"),
+            )
             .func(part_2)
             .build()?,
     ];
@@ -68,55 +71,34 @@ pub fn day_1() -> BoxResult<DayCommand> {
         .build()
 }
 
-pub fn part_1(args: ArgMatches) -> BoxResult<()> {
-    let f = FileReader::try_from(args)?;
-
-    let mut max = Calories::new();
-    let mut buf = String::new();
-
-    for i in f {
-        if i.is_empty() {
-            let c = Calories::try_from(buf.clone());
-            if let Ok(c) = c {
-                if c > max {
-                    max = c;
-                }
-            }
-            buf.clear();
-        }
-        buf += &i;
-        buf += " ";
-    }
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<Calories>> {
+    let input = Input::try_from(args)?;
 
-    println!("Maximum calories found was {}", max);
+    let max = input
+        .groups()
+        .map(Calories::try_from)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .max()
+        .unwrap_or(Calories::new());
 
-    Ok(())
+    Ok(CommandResponse::new(max, "Maximum calories found was"))
 }
 
-pub fn part_2(args: ArgMatches) -> BoxResult<()> {
-    let f = FileReader::try_from(args)?;
-
-    let mut buf = String::new();
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<Calories>> {
+    let top = args.get::<usize>("top")?;
+    let input = Input::try_from(args)?;
 
-    let mut list = Vec::new();
-
-    for i in f {
-        if i.is_empty() {
-            let c = Calories::try_from(buf.clone());
-            if let Ok(c) = c {
-                list.push(c);
-                buf.clear()
-                }
-        }
-        buf += &i;
-        buf += " ";
-    }
+    let mut list = input
+        .groups()
+        .map(Calories::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
 
     list.sort_by(|a, b| b.cmp(a));
+    info!(elves = list.len(), "parsed calories");
 
-    println!(
-        "Top 3 elves have {} calories",
-        list[..3].iter().copied().sum::<Calories>()
-    );
-    Ok(())
+    Ok(CommandResponse::new(
+        list.iter().take(top).copied().sum::<Calories>(),
+        format!("The calories carried by the top {} elves total", top),
+    ))
 }
@@ -0,0 +1,163 @@
+use std::{fmt::Display, num::ParseIntError};
+
+use crate::{error::OcrError, helpers::ocr::read_letters, prelude::*};
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl TryFrom<String> for Instruction {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(s: String) -> BoxResult<Self> {
+        let mut split = s.split_whitespace();
+        match (split.next(), split.next()) {
+            (Some("noop"), None) => Ok(Instruction::Noop),
+            (Some("addx"), Some(v)) => Ok(Instruction::Addx(
+                v.parse()
+                    .map_err(|e: ParseIntError| format!("invalid addx operand {}: {}", v, e))?,
+            )),
+            _ => Err(format!("unknown instruction {}", s).into()),
+        }
+    }
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+pub struct Cpu {
+    x: i32,
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Self { x: 1 }
+    }
+
+    /// Runs the program, returning the value of the x register during each cycle.
+    pub fn run(&mut self, program: &[Instruction]) -> Vec<i32> {
+        let mut history = Vec::new();
+
+        for instruction in program {
+            for _ in 0..instruction.cycles() {
+                history.push(self.x);
+            }
+
+            if let Instruction::Addx(v) = instruction {
+                self.x += v;
+            }
+        }
+
+        history
+    }
+}
+
+pub struct Crt {
+    pixels: Vec<Vec<bool>>,
+}
+
+impl From<&[i32]> for Crt {
+    fn from(history: &[i32]) -> Self {
+        let mut pixels = vec![vec![false; SCREEN_WIDTH]; SCREEN_HEIGHT];
+
+        for (cycle, x) in history
+            .iter()
+            .enumerate()
+            .take(SCREEN_WIDTH * SCREEN_HEIGHT)
+        {
+            let (row, col) = (cycle / SCREEN_WIDTH, cycle % SCREEN_WIDTH);
+            pixels[row][col] = (col as i32 - x).abs() <= 1;
+        }
+
+        Self { pixels }
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.pixels {
+            let line: String = row.iter().map(|x| if *x { '#' } else { '.' }).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl Crt {
+    pub fn read(&self) -> Result<String, OcrError> {
+        read_letters(&self.pixels)
+    }
+}
+
+fn read_program(args: PartArgs) -> BoxResult<Vec<Instruction>> {
+    let f = FileReader::try_from(args)?;
+    let program = f
+        .filter(|x| !x.is_empty())
+        .map(Instruction::try_from)
+        .collect::<BoxResult<Vec<_>>>()?;
+
+    info!(instructions = program.len(), "parsed program");
+    Ok(program)
+}
+
+pub fn day_10() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_10")
+        .parts(&mut parts)
+        .about("the solution to the day 10 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
+    let program = read_program(args)?;
+    let history = Cpu::new().run(&program);
+
+    let strength: i32 = history
+        .iter()
+        .enumerate()
+        .map(|(n, x)| (n as i32 + 1, x))
+        .filter(|(cycle, _)| *cycle <= 220 && (cycle - 20) % 40 == 0)
+        .map(|(cycle, x)| cycle * x)
+        .sum();
+
+    Ok(CommandResponse::new(
+        strength,
+        "The sum of the signal strengths is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<String>> {
+    let program = read_program(args)?;
+    let history = Cpu::new().run(&program);
+    let crt = Crt::from(history.as_slice());
+
+    Ok(CommandResponse::new(
+        crt.read()?,
+        format!("{}The CRT displays", crt),
+    ))
+}
@@ -0,0 +1,310 @@
+use std::{collections::VecDeque, fmt::Display, iter::Peekable, str::Chars};
+
+use crate::{error::OperationError, prelude::*};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Old,
+    Number(u64),
+    Plus,
+    Star,
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            chars: s.chars().peekable(),
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, OperationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.chars.next_if(|x| x.is_whitespace()).is_some() {}
+
+        let c = self.chars.next()?;
+        let token = match c {
+            '+' => Ok(Token::Plus),
+            '*' => Ok(Token::Star),
+            '0'..='9' => {
+                let mut n = c.to_digit(10).unwrap() as u64;
+                while let Some(d) = self.chars.next_if(|x| x.is_ascii_digit()) {
+                    n = match n
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(d.to_digit(10).unwrap() as u64))
+                    {
+                        Some(n) => n,
+                        None => return Some(Err(OperationError::Overflow("literal".into()))),
+                    };
+                }
+                Ok(Token::Number(n))
+            }
+            c if c.is_alphabetic() => {
+                let mut ident = c.to_string();
+                while let Some(c) = self.chars.next_if(|x| x.is_alphanumeric()) {
+                    ident.push(c);
+                }
+                match ident.as_str() {
+                    "old" => Ok(Token::Old),
+                    _ => Err(OperationError::UnexpectedToken(ident)),
+                }
+            }
+            c => Err(OperationError::UnexpectedToken(c.to_string())),
+        };
+
+        Some(token)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Old,
+    Constant(u64),
+    Add(Box<Operation>, Box<Operation>),
+    Mul(Box<Operation>, Box<Operation>),
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = OperationError;
+    fn try_from(s: &str) -> Result<Self, OperationError> {
+        let tokens = Lexer::new(s).collect::<Result<Vec<_>, _>>()?;
+        let mut parser = Parser {
+            tokens: tokens.into_iter().peekable(),
+        };
+
+        let op = parser.sum()?;
+        match parser.tokens.next() {
+            Some(t) => Err(OperationError::UnexpectedToken(format!("{:?}", t))),
+            None => Ok(op),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn sum(&mut self) -> Result<Operation, OperationError> {
+        let mut lhs = self.product()?;
+        while self.tokens.next_if_eq(&Token::Plus).is_some() {
+            lhs = Operation::Add(Box::new(lhs), Box::new(self.product()?));
+        }
+        Ok(lhs)
+    }
+
+    fn product(&mut self) -> Result<Operation, OperationError> {
+        let mut lhs = self.atom()?;
+        while self.tokens.next_if_eq(&Token::Star).is_some() {
+            lhs = Operation::Mul(Box::new(lhs), Box::new(self.atom()?));
+        }
+        Ok(lhs)
+    }
+
+    fn atom(&mut self) -> Result<Operation, OperationError> {
+        match self.tokens.next() {
+            Some(Token::Old) => Ok(Operation::Old),
+            Some(Token::Number(n)) => Ok(Operation::Constant(n)),
+            Some(t) => Err(OperationError::UnexpectedToken(format!("{:?}", t))),
+            None => Err(OperationError::UnexpectedEnd),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Old => write!(f, "old"),
+            Operation::Constant(n) => write!(f, "{}", n),
+            Operation::Add(lhs, rhs) => write!(f, "{} + {}", lhs, rhs),
+            Operation::Mul(lhs, rhs) => write!(f, "{} * {}", lhs, rhs),
+        }
+    }
+}
+
+impl Operation {
+    pub fn eval(&self, old: u64) -> Result<u64, OperationError> {
+        let res = match self {
+            Operation::Old => Some(old),
+            Operation::Constant(n) => Some(*n),
+            Operation::Add(lhs, rhs) => lhs.eval(old)?.checked_add(rhs.eval(old)?),
+            Operation::Mul(lhs, rhs) => lhs.eval(old)?.checked_mul(rhs.eval(old)?),
+        };
+        res.ok_or_else(|| OperationError::Overflow(format!("{} with old = {}", self, old)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+    inspections: usize,
+}
+
+fn field<'a>(line: Option<&'a String>, prefix: &str) -> BoxResult<&'a str> {
+    let line = line.ok_or(format!("missing line starting with {}", prefix))?;
+    Ok(line
+        .strip_prefix(prefix)
+        .ok_or(format!("expected {} but got {}", prefix, line))?
+        .trim())
+}
+
+impl TryFrom<&[String]> for Monkey {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(lines: &[String]) -> BoxResult<Self> {
+        let mut lines = lines.iter();
+        field(lines.next(), "Monkey")?;
+
+        let items = field(lines.next(), "Starting items:")?
+            .split(',')
+            .filter(|x| !x.trim().is_empty())
+            .map(|x| x.trim().parse::<u64>())
+            .collect::<Result<VecDeque<_>, _>>()?;
+        let operation = Operation::try_from(field(lines.next(), "Operation: new =")?)?;
+        let divisor = field(lines.next(), "Test: divisible by")?.parse()?;
+        let if_true = field(lines.next(), "If true: throw to monkey")?.parse()?;
+        let if_false = field(lines.next(), "If false: throw to monkey")?.parse()?;
+
+        if divisor == 0 {
+            return Err("monkey can not test divisibility by 0".into());
+        }
+
+        Ok(Self {
+            items,
+            operation,
+            divisor,
+            if_true,
+            if_false,
+            inspections: 0,
+        })
+    }
+}
+
+fn read_monkeys(args: PartArgs) -> BoxResult<Vec<Monkey>> {
+    let f = FileReader::try_from(args)?;
+    let lines = f.collect::<Vec<_>>();
+
+    let monkeys = lines
+        .split(|x| x.is_empty())
+        .filter(|x| !x.is_empty())
+        .map(Monkey::try_from)
+        .collect::<BoxResult<Vec<_>>>()?;
+
+    for (i, m) in monkeys.iter().enumerate() {
+        if m.if_true >= monkeys.len() || m.if_false >= monkeys.len() {
+            return Err(format!(
+                "monkey throws to a monkey out of range 0..{}",
+                monkeys.len()
+            )
+            .into());
+        }
+
+        // a monkey throwing to itself would keep catching the same item forever
+        if m.if_true == i || m.if_false == i {
+            return Err(format!("monkey {} throws to itself", i).into());
+        }
+    }
+
+    info!(monkeys = monkeys.len(), "parsed monkeys");
+    Ok(monkeys)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn simulate(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    relief: impl Fn(u64) -> u64,
+    progress: &Progress,
+) -> BoxResult<usize> {
+    progress.set_total(rounds as u64);
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            while let Some(item) = monkeys[i].items.pop_front() {
+                let monkey = &mut monkeys[i];
+                monkey.inspections += 1;
+
+                let worry = relief(monkey.operation.eval(item)?);
+                let target = if worry.is_multiple_of(monkey.divisor) {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+
+                monkeys[target].items.push_back(worry);
+            }
+        }
+        progress.inc(1);
+    }
+
+    let mut inspections = monkeys.iter().map(|x| x.inspections).collect::<Vec<_>>();
+    inspections.sort_by(|a, b| b.cmp(a));
+
+    Ok(inspections.iter().take(2).product())
+}
+
+pub fn day_11() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_11")
+        .parts(&mut parts)
+        .about("the solution to the day 11 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let progress = args.progress();
+    let mut monkeys = read_monkeys(args)?;
+    let business = simulate(&mut monkeys, 20, |x| x / 3, &progress)?;
+
+    Ok(CommandResponse::new(
+        business,
+        "The level of monkey business after 20 rounds is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let progress = args.progress();
+    let mut monkeys = read_monkeys(args)?;
+    let modulus = monkeys.iter().try_fold(1u64, |acc, x| {
+        (acc / gcd(acc, x.divisor))
+            .checked_mul(x.divisor)
+            .ok_or_else(|| OperationError::Overflow("the lcm of the divisors".into()))
+    })?;
+    debug!(modulus, "keeping worry levels below the divisors' lcm");
+    let business = simulate(&mut monkeys, 10_000, |x| x % modulus, &progress)?;
+
+    Ok(CommandResponse::new(
+        business,
+        "The level of monkey business after 10000 rounds is",
+    ))
+}
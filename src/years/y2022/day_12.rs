@@ -0,0 +1,221 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use crate::prelude::*;
+
+type Position = (usize, usize);
+
+pub struct HeightMap {
+    heights: Vec<Vec<u8>>,
+    start: Position,
+    end: Position,
+}
+
+impl TryFrom<Vec<String>> for HeightMap {
+    type Error = &'static str;
+    fn try_from(lines: Vec<String>) -> Result<Self, &'static str> {
+        let mut start = None;
+        let mut end = None;
+        let mut heights = Vec::new();
+
+        for (row, line) in lines.iter().filter(|x| !x.is_empty()).enumerate() {
+            let mut heights_row = Vec::new();
+            for (col, c) in line.chars().enumerate() {
+                let height = match c {
+                    'S' => {
+                        start = Some((row, col));
+                        b'a'
+                    }
+                    'E' => {
+                        end = Some((row, col));
+                        b'z'
+                    }
+                    'a'..='z' => c as u8,
+                    _ => return Err("height map contains an unknown elevation"),
+                };
+                heights_row.push(height);
+            }
+            heights.push(heights_row);
+        }
+
+        // the search and the overlay size their grids from the first row
+        if heights.iter().any(|x| x.len() != heights[0].len()) {
+            return Err("height map rows are not all the same length");
+        }
+
+        Ok(Self {
+            heights,
+            start: start.ok_or("height map has no start position")?,
+            end: end.ok_or("height map has no end position")?,
+        })
+    }
+}
+
+impl HeightMap {
+    fn neighbours(&self, (row, col): Position) -> impl Iterator<Item = Position> + '_ {
+        [(0, 1), (2, 1), (1, 0), (1, 2)]
+            .into_iter()
+            .filter_map(move |(dr, dc)| {
+                let row = (row + dr).checked_sub(1)?;
+                let col = (col + dc).checked_sub(1)?;
+                self.heights.get(row)?.get(col)?;
+                Some((row, col))
+            })
+    }
+
+    fn height(&self, (row, col): Position) -> u8 {
+        self.heights[row][col]
+    }
+
+    /// Searches backwards from the end so every cell found can climb to the end,
+    /// returning the path from the first cell matching `is_goal` to the end.
+    pub fn shortest_path(&self, is_goal: impl Fn(Position) -> bool) -> Option<Route> {
+        let mut previous = vec![vec![None; self.heights[0].len()]; self.heights.len()];
+        let mut queue = VecDeque::from([self.end]);
+        let mut visited = 0;
+        previous[self.end.0][self.end.1] = Some(self.end);
+
+        while let Some(current) = queue.pop_front() {
+            visited += 1;
+            if is_goal(current) {
+                debug!(visited, "found a path");
+                let mut steps = vec![current];
+                let mut position = current;
+                while position != self.end {
+                    position = previous[position.0][position.1].unwrap();
+                    steps.push(position);
+                }
+                return Some(Route { steps });
+            }
+
+            for next in self.neighbours(current) {
+                if previous[next.0][next.1].is_none()
+                    && self.height(current) <= self.height(next) + 1
+                {
+                    previous[next.0][next.1] = Some(current);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        debug!(visited, "no path found");
+        None
+    }
+
+    pub fn overlay<'a>(&'a self, path: &'a Route) -> RouteOverlay<'a> {
+        RouteOverlay { map: self, path }
+    }
+}
+
+pub struct Route {
+    steps: Vec<Position>,
+}
+
+impl Route {
+    pub fn len(&self) -> usize {
+        self.steps.len() - 1
+    }
+}
+
+pub struct RouteOverlay<'a> {
+    map: &'a HeightMap,
+    path: &'a Route,
+}
+
+impl Display for RouteOverlay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = vec![vec!['.'; self.map.heights[0].len()]; self.map.heights.len()];
+
+        for step in self.path.steps.windows(2) {
+            let ((row, col), next) = (step[0], step[1]);
+            grid[row][col] = match next {
+                n if n.1 > col => '>',
+                n if n.1 < col => '<',
+                n if n.0 > row => 'v',
+                _ => '^',
+            };
+        }
+        grid[self.map.end.0][self.map.end.1] = 'E';
+
+        for row in grid {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+fn read_map(args: PartArgs) -> BoxResult<HeightMap> {
+    let f = FileReader::try_from(args)?;
+    let map = HeightMap::try_from(f.collect::<Vec<_>>())?;
+
+    info!(
+        rows = map.heights.len(),
+        columns = map.heights[0].len(),
+        "parsed height map"
+    );
+    Ok(map)
+}
+
+fn print_path_arg() -> PartArg {
+    PartArg::flag("print path")
+        .short('p')
+        .help("print the path found overlaid on the map")
+}
+
+pub fn day_12() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .arg(print_path_arg())
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .arg(print_path_arg())
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_12")
+        .parts(&mut parts)
+        .about("the solution to the day 12 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let print_path = args.get::<bool>("print path")?;
+    let map = read_map(args)?;
+
+    let path = map
+        .shortest_path(|x| x == map.start)
+        .ok_or("no path from the start to the end")?;
+
+    if print_path {
+        print!("{}", map.overlay(&path));
+    }
+    Ok(CommandResponse::new(
+        path.len(),
+        "The fewest steps from the start to the end is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let print_path = args.get::<bool>("print path")?;
+    let map = read_map(args)?;
+
+    let path = map
+        .shortest_path(|x| map.height(x) == b'a')
+        .ok_or("no path from any lowest point to the end")?;
+
+    if print_path {
+        print!("{}", map.overlay(&path));
+    }
+    Ok(CommandResponse::new(
+        path.len(),
+        "The fewest steps from any lowest point to the end is",
+    ))
+}
@@ -0,0 +1,265 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::{error::PacketParseError, prelude::*};
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+struct PacketParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl PacketParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn unexpected(&self) -> PacketParseError {
+        match self.peek() {
+            Some(c) => PacketParseError::UnexpectedChar(c as char, self.pos),
+            None => PacketParseError::UnexpectedEnd,
+        }
+    }
+
+    fn packet(&mut self) -> Result<Packet, PacketParseError> {
+        match self.peek() {
+            Some(b'[') => self.list(),
+            Some(b'0'..=b'9') => self.integer(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn integer(&mut self) -> Result<Packet, PacketParseError> {
+        let start = self.pos;
+        let mut value: u32 = 0;
+
+        while let Some(c @ b'0'..=b'9') = self.peek() {
+            value = value
+                .checked_mul(10)
+                .and_then(|x| x.checked_add((c - b'0') as u32))
+                .ok_or(PacketParseError::IntegerOverflow(start))?;
+            self.pos += 1;
+        }
+
+        Ok(Packet::Integer(value))
+    }
+
+    fn list(&mut self) -> Result<Packet, PacketParseError> {
+        self.pos += 1;
+        let mut items = Vec::new();
+
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Packet::List(items));
+        }
+
+        loop {
+            items.push(self.packet()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Packet::List(items));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = PacketParseError;
+    fn from_str(s: &str) -> Result<Self, PacketParseError> {
+        let mut parser = PacketParser {
+            bytes: s.as_bytes(),
+            pos: 0,
+        };
+
+        let packet = parser.packet()?;
+        match parser.peek() {
+            None => Ok(packet),
+            Some(_) => Err(parser.unexpected()),
+        }
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Integer(x) => write!(f, "{}", x),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (n, item) in items.iter().enumerate() {
+                    if n > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Integer(a), Packet::List(b)) => [Packet::Integer(*a)][..].cmp(b),
+            (Packet::List(a), Packet::Integer(b)) => a[..].cmp(&[Packet::Integer(*b)]),
+        }
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn read_packets(args: PartArgs) -> BoxResult<Vec<Packet>> {
+    let input = Input::try_from(args)?;
+    let packets = input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse::<Packet>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    info!(packets = packets.len(), "parsed packets");
+    Ok(packets)
+}
+
+pub fn day_13() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_13")
+        .parts(&mut parts)
+        .about("the solution to the day 13 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let packets = read_packets(args)?;
+
+    let sum: usize = packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| pair.len() == 2 && pair[0] < pair[1])
+        .map(|(n, _)| n + 1)
+        .sum();
+
+    Ok(CommandResponse::new(
+        sum,
+        "The sum of the indices of ordered pairs is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let mut packets = read_packets(args)?;
+    let dividers = ["[[2]]".parse::<Packet>()?, "[[6]]".parse::<Packet>()?];
+
+    packets.extend(dividers.iter().cloned());
+    packets.sort();
+
+    let key: usize = dividers
+        .iter()
+        .map(|d| packets.binary_search(d).unwrap() + 1)
+        .product();
+
+    Ok(CommandResponse::new(
+        key,
+        "The decoder key for the distress signal is",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A xorshift generator, so the fuzz cases are the same on every run.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+    }
+
+    fn random_packet(rng: &mut Rng, depth: u32) -> Packet {
+        if depth == 0 || rng.below(3) == 0 {
+            return match rng.below(4) {
+                0 => Packet::Integer(u32::MAX),
+                _ => Packet::Integer(rng.below(20) as u32),
+            };
+        }
+
+        let len = rng.below(5);
+        Packet::List((0..len).map(|_| random_packet(rng, depth - 1)).collect())
+    }
+
+    #[test]
+    fn display_round_trips() -> Result<(), PacketParseError> {
+        let mut rng = Rng(0x2022_1213);
+
+        for _ in 0..10_000 {
+            let packet = Packet::List(vec![random_packet(&mut rng, 6)]);
+            let s = packet.to_string();
+
+            // packets compare by the puzzle ordering, where [1] == 1, so compare the text
+            assert_eq!(s.parse::<Packet>()?.to_string(), s);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_packets() {
+        let cases = [
+            ("", PacketParseError::UnexpectedEnd),
+            ("[", PacketParseError::UnexpectedEnd),
+            ("[1,", PacketParseError::UnexpectedEnd),
+            ("[1]]", PacketParseError::UnexpectedChar(']', 3)),
+            ("[1,,2]", PacketParseError::UnexpectedChar(',', 3)),
+            ("[1 2]", PacketParseError::UnexpectedChar(' ', 2)),
+            ("[a]", PacketParseError::UnexpectedChar('a', 1)),
+            ("[4294967296]", PacketParseError::IntegerOverflow(1)),
+        ];
+
+        for (s, err) in cases {
+            assert_eq!(s.parse::<Packet>().unwrap_err(), err, "parsing {:?}", s);
+        }
+    }
+}
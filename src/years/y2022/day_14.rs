@@ -0,0 +1,203 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::prelude::*;
+
+type Point = (i32, i32);
+
+const SOURCE: Point = (500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+pub struct Cave {
+    tiles: HashMap<Point, Tile>,
+    lowest_rock: i32,
+    floor: Option<i32>,
+}
+
+fn parse_point(s: &str) -> BoxResult<Point> {
+    let (x, y) = s
+        .trim()
+        .split_once(',')
+        .ok_or(format!("invalid point {}", s))?;
+    Ok((x.parse()?, y.parse()?))
+}
+
+impl TryFrom<Vec<String>> for Cave {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(lines: Vec<String>) -> BoxResult<Self> {
+        let mut tiles = HashMap::new();
+
+        for line in lines.iter().filter(|x| !x.is_empty()) {
+            let points = line
+                .split("->")
+                .map(parse_point)
+                .collect::<BoxResult<Vec<_>>>()?;
+
+            for segment in points.windows(2) {
+                let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+                if x1 != x2 && y1 != y2 {
+                    return Err(format!("rock path {} is not axis aligned", line).into());
+                }
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        tiles.insert((x, y), Tile::Rock);
+                    }
+                }
+            }
+        }
+
+        let lowest_rock = tiles
+            .keys()
+            .map(|(_, y)| *y)
+            .max()
+            .ok_or("cave has no rocks")?;
+
+        Ok(Self {
+            tiles,
+            lowest_rock,
+            floor: None,
+        })
+    }
+}
+
+impl Cave {
+    pub fn with_floor(mut self) -> Self {
+        self.floor = Some(self.lowest_rock + 2);
+        self
+    }
+
+    fn is_blocked(&self, point: Point) -> bool {
+        self.floor == Some(point.1) || self.tiles.contains_key(&point)
+    }
+
+    /// Drops a single unit of sand from the source, returning where it came to
+    /// rest or `None` if it fell into the abyss or the source is blocked.
+    pub fn drop_sand(&mut self) -> Option<Point> {
+        if self.is_blocked(SOURCE) {
+            return None;
+        }
+
+        let (mut x, mut y) = SOURCE;
+        loop {
+            if self.floor.is_none() && y > self.lowest_rock {
+                return None;
+            }
+
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|nx| !self.is_blocked((*nx, y + 1)))
+            {
+                Some(nx) => (x, y) = (nx, y + 1),
+                None => {
+                    self.tiles.insert((x, y), Tile::Sand);
+                    return Some((x, y));
+                }
+            }
+        }
+    }
+
+    pub fn fill(&mut self, dump_frames: bool) -> usize {
+        let mut count = 0;
+        while self.drop_sand().is_some() {
+            count += 1;
+            if dump_frames {
+                println!("After {} units of sand:\n{}", count, self);
+            }
+        }
+        count
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points = self.tiles.keys().chain([&SOURCE]);
+        let min_x = points.clone().map(|(x, _)| *x).min().unwrap();
+        let max_x = points.map(|(x, _)| *x).max().unwrap();
+        let max_y = self.floor.unwrap_or(self.lowest_rock);
+
+        for y in 0..=max_y {
+            let line: String = (min_x..=max_x)
+                .map(|x| match self.tiles.get(&(x, y)) {
+                    Some(Tile::Rock) => '#',
+                    Some(Tile::Sand) => 'o',
+                    None if self.floor == Some(y) => '#',
+                    None if (x, y) == SOURCE => '+',
+                    None => '.',
+                })
+                .collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn read_cave(args: PartArgs) -> BoxResult<Cave> {
+    let f = FileReader::try_from(args)?;
+    let cave = Cave::try_from(f.collect::<Vec<_>>())?;
+
+    info!(
+        rocks = cave.tiles.len(),
+        lowest_rock = cave.lowest_rock,
+        "parsed cave"
+    );
+    Ok(cave)
+}
+
+fn dump_frames_arg() -> PartArg {
+    PartArg::flag("dump frames")
+        .short('d')
+        .help("print the cave after each unit of sand comes to rest")
+}
+
+pub fn day_14() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .arg(dump_frames_arg())
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .arg(dump_frames_arg())
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_14")
+        .parts(&mut parts)
+        .about("the solution to the day 14 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let dump_frames = args.get::<bool>("dump frames")?;
+    let mut cave = read_cave(args)?;
+
+    let count = cave.fill(dump_frames);
+
+    Ok(CommandResponse::new(
+        count,
+        "The units of sand that came to rest before sand fell into the abyss were",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let dump_frames = args.get::<bool>("dump frames")?;
+    let mut cave = read_cave(args)?.with_floor();
+
+    let count = cave.fill(dump_frames);
+
+    Ok(CommandResponse::new(
+        count,
+        "The units of sand that came to rest before the source was blocked were",
+    ))
+}
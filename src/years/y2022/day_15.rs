@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use crate::prelude::*;
+
+type Point = (i64, i64);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sensor {
+    position: Point,
+    beacon: Point,
+    radius: i64,
+}
+
+fn parse_point(s: &str) -> BoxResult<Point> {
+    let (x, y) = s
+        .trim()
+        .split_once(", ")
+        .ok_or(format!("invalid coordinates {}", s))?;
+    let x = x
+        .strip_prefix("x=")
+        .ok_or(format!("invalid x coordinate {}", x))?;
+    let y = y
+        .strip_prefix("y=")
+        .ok_or(format!("invalid y coordinate {}", y))?;
+    Ok((x.parse()?, y.parse()?))
+}
+
+impl TryFrom<String> for Sensor {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(s: String) -> BoxResult<Self> {
+        let (sensor, beacon) = s
+            .strip_prefix("Sensor at ")
+            .and_then(|x| x.split_once(": closest beacon is at "))
+            .ok_or(format!("invalid sensor report {}", s))?;
+
+        let position = parse_point(sensor)?;
+        let beacon = parse_point(beacon)?;
+        let radius = (position.0 - beacon.0).abs() + (position.1 - beacon.1).abs();
+
+        Ok(Self {
+            position,
+            beacon,
+            radius,
+        })
+    }
+}
+
+impl Sensor {
+    fn row_coverage(&self, row: i64) -> Option<(i64, i64)> {
+        let reach = self.radius - (self.position.1 - row).abs();
+        (reach >= 0).then_some((self.position.0 - reach, self.position.0 + reach))
+    }
+}
+
+/// Returns the merged, sorted, inclusive intervals covered by the sensors on a row.
+fn covered(sensors: &[Sensor], row: i64) -> Vec<(i64, i64)> {
+    let mut intervals = sensors
+        .iter()
+        .filter_map(|x| x.row_coverage(row))
+        .collect::<Vec<_>>();
+    intervals.sort();
+
+    let mut merged: Vec<(i64, i64)> = Vec::new();
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn read_sensors(args: PartArgs) -> BoxResult<Vec<Sensor>> {
+    let f = FileReader::try_from(args)?;
+    let sensors = f
+        .filter(|x| !x.is_empty())
+        .map(Sensor::try_from)
+        .collect::<BoxResult<Vec<_>>>()?;
+
+    info!(sensors = sensors.len(), "parsed sensors");
+    Ok(sensors)
+}
+
+pub fn day_15() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .arg(
+                PartArg::int("row", 2_000_000)
+                    .short('r')
+                    .help("the row to count covered positions on"),
+            )
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .arg(
+                PartArg::int("bound", 4_000_000)
+                    .short('b')
+                    .help("the largest coordinate the distress beacon can be at"),
+            )
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_15")
+        .parts(&mut parts)
+        .about("the solution to the day 15 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i64>> {
+    let row = args.get::<i64>("row")?;
+    let sensors = read_sensors(args)?;
+
+    let covered = covered(&sensors, row);
+    let beacons = sensors
+        .iter()
+        .map(|x| x.beacon)
+        .filter(|(x, y)| *y == row && covered.iter().any(|(s, e)| s <= x && x <= e))
+        .collect::<HashSet<_>>();
+
+    let count = covered.iter().map(|(s, e)| e - s + 1).sum::<i64>() - beacons.len() as i64;
+
+    Ok(CommandResponse::new(
+        count,
+        format!(
+            "The positions on row {} that can not contain a beacon are",
+            row
+        ),
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<i64>> {
+    let bound = args.get::<i64>("bound")?;
+    let sensors = read_sensors(args)?;
+
+    let (x, y) = (0..=bound)
+        .find_map(|row| {
+            let mut x = 0;
+            for (start, end) in covered(&sensors, row) {
+                if start > x {
+                    break;
+                }
+                x = x.max(end + 1);
+            }
+            (x <= bound).then_some((x, row))
+        })
+        .ok_or("no uncovered position found for the distress beacon")?;
+
+    Ok(CommandResponse::new(
+        x * 4_000_000 + y,
+        "The tuning frequency of the distress beacon is",
+    ))
+}
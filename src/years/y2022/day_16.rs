@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+const START: &str = "AA";
+
+struct Valve {
+    name: String,
+    flow: u32,
+    tunnels: Vec<String>,
+}
+
+impl TryFrom<String> for Valve {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(s: String) -> BoxResult<Self> {
+        let (valve, tunnels) = s
+            .split_once("; ")
+            .ok_or(format!("invalid valve report {}", s))?;
+        let (name, flow) = valve
+            .strip_prefix("Valve ")
+            .and_then(|x| x.split_once(" has flow rate="))
+            .ok_or(format!("invalid valve {}", valve))?;
+        let tunnels = ["tunnels lead to valves ", "tunnel leads to valve "]
+            .iter()
+            .find_map(|x| tunnels.strip_prefix(x))
+            .ok_or(format!("invalid tunnels {}", tunnels))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            flow: flow.parse()?,
+            tunnels: tunnels.split(", ").map(|x| x.to_string()).collect(),
+        })
+    }
+}
+
+/// The tunnel graph reduced to the start valve and the valves worth opening,
+/// with the travel time between every pair of them.
+pub struct ValveGraph {
+    flows: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+    start: usize,
+}
+
+impl TryFrom<Vec<Valve>> for ValveGraph {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(valves: Vec<Valve>) -> BoxResult<Self> {
+        let index = valves
+            .iter()
+            .enumerate()
+            .map(|(n, x)| (x.name.as_str(), n))
+            .collect::<HashMap<_, _>>();
+
+        let n = valves.len();
+        let mut distances = vec![vec![u32::MAX / 2; n]; n];
+        for (i, valve) in valves.iter().enumerate() {
+            distances[i][i] = 0;
+            for tunnel in &valve.tunnels {
+                let j = *index
+                    .get(tunnel.as_str())
+                    .ok_or(format!("tunnel leads to unknown valve {}", tunnel))?;
+                distances[i][j] = 1;
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    distances[i][j] = distances[i][j].min(distances[i][k] + distances[k][j]);
+                }
+            }
+        }
+
+        let start = *index.get(START).ok_or("no start valve found")?;
+        let mut kept = (0..n).filter(|x| valves[*x].flow > 0).collect::<Vec<_>>();
+        if kept.len() > 20 {
+            return Err(
+                format!("too many valves with flow to search, found {}", kept.len()).into(),
+            );
+        }
+        kept.push(start);
+
+        Ok(Self {
+            flows: kept.iter().map(|x| valves[*x].flow).collect(),
+            distances: kept
+                .iter()
+                .map(|i| kept.iter().map(|j| distances[*i][*j]).collect())
+                .collect(),
+            start: kept.len() - 1,
+        })
+    }
+}
+
+impl ValveGraph {
+    fn valves(&self) -> usize {
+        self.flows.len() - 1
+    }
+
+    /// Returns the most pressure that can be released for every set of opened valves,
+    /// reporting progress for each valve that is opened first.
+    pub fn best_by_opened(&self, minutes: u32, progress: &Progress) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.valves()];
+        progress.set_total(self.valves() as u64);
+
+        for first in 0..self.valves() {
+            self.open(first, self.start, minutes, 0, 0, &mut best);
+            progress.set_best(best.iter().max().unwrap_or(&0));
+            progress.inc(1);
+        }
+        best
+    }
+
+    fn search(&self, position: usize, time: u32, opened: usize, released: u32, best: &mut [u32]) {
+        best[opened] = best[opened].max(released);
+
+        for next in 0..self.valves() {
+            self.open(next, position, time, opened, released, best);
+        }
+    }
+
+    fn open(
+        &self,
+        next: usize,
+        position: usize,
+        time: u32,
+        opened: usize,
+        released: u32,
+        best: &mut [u32],
+    ) {
+        let cost = self.distances[position][next] + 1;
+        if opened & (1 << next) != 0 || cost >= time {
+            return;
+        }
+
+        let time = time - cost;
+        self.search(
+            next,
+            time,
+            opened | (1 << next),
+            released + time * self.flows[next],
+            best,
+        );
+    }
+}
+
+fn read_graph(args: PartArgs) -> BoxResult<ValveGraph> {
+    let f = FileReader::try_from(args)?;
+    let valves = f
+        .filter(|x| !x.is_empty())
+        .map(Valve::try_from)
+        .collect::<BoxResult<Vec<_>>>()?;
+
+    let total = valves.len();
+    let graph = ValveGraph::try_from(valves)?;
+
+    info!(
+        valves = total,
+        worth_opening = graph.valves(),
+        "parsed valves"
+    );
+    Ok(graph)
+}
+
+pub fn day_16() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_16")
+        .parts(&mut parts)
+        .about("the solution to the day 16 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<u32>> {
+    let progress = args.progress();
+    let graph = read_graph(args)?;
+
+    let pressure = graph
+        .best_by_opened(30, &progress)
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+
+    Ok(CommandResponse::new(
+        pressure,
+        "The most pressure that can be released alone is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<u32>> {
+    let progress = args.progress();
+    let graph = read_graph(args)?;
+    let best = graph.best_by_opened(26, &progress);
+
+    // best_subset[mask] is the most released by opening any subset of mask
+    let mut best_subset = best.clone();
+    for bit in 0..graph.valves() {
+        for mask in 0..best_subset.len() {
+            if mask & (1 << bit) != 0 {
+                best_subset[mask] = best_subset[mask].max(best_subset[mask ^ (1 << bit)]);
+            }
+        }
+    }
+
+    let all = best.len() - 1;
+    let pressure = best
+        .iter()
+        .enumerate()
+        .map(|(mask, x)| x + best_subset[all ^ mask])
+        .max()
+        .unwrap_or_default();
+
+    Ok(CommandResponse::new(
+        pressure,
+        "The most pressure that can be released with an elephant is",
+    ))
+}
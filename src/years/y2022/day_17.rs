@@ -0,0 +1,192 @@
+use crate::{helpers::cycle::CycleDetector, prelude::*};
+
+const CHAMBER_WIDTH: usize = 7;
+const FULL_ROW: u8 = (1 << CHAMBER_WIDTH) - 1;
+
+// rows are listed bottom first, with bit n set for column n from the left
+const ROCKS: [&[u8]; 5] = [
+    &[0b1111],
+    &[0b010, 0b111, 0b010],
+    &[0b111, 0b100, 0b100],
+    &[0b1, 0b1, 0b1, 0b1],
+    &[0b11, 0b11],
+];
+
+#[derive(Debug, Clone, Copy)]
+enum Jet {
+    Left,
+    Right,
+}
+
+impl TryFrom<u8> for Jet {
+    type Error = &'static str;
+    fn try_from(c: u8) -> Result<Self, &'static str> {
+        match c {
+            b'<' => Ok(Jet::Left),
+            b'>' => Ok(Jet::Right),
+            _ => Err("char not found for jet direction"),
+        }
+    }
+}
+
+pub struct Chamber {
+    rows: Vec<u8>,
+    jets: Vec<Jet>,
+    jet: usize,
+    rock: usize,
+}
+
+impl Chamber {
+    fn new(jets: Vec<Jet>) -> Self {
+        Self {
+            rows: Vec::new(),
+            jets,
+            jet: 0,
+            rock: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(n, row)| self.rows.get(bottom + n).is_some_and(|x| x & row != 0))
+    }
+
+    fn push(rock: &[u8], jet: Jet) -> Option<Vec<u8>> {
+        match jet {
+            Jet::Left if rock.iter().all(|x| x & 1 == 0) => {
+                Some(rock.iter().map(|x| x >> 1).collect())
+            }
+            Jet::Right if rock.iter().all(|x| (x << 1) & !FULL_ROW == 0) => {
+                Some(rock.iter().map(|x| x << 1).collect())
+            }
+            _ => None,
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rock].iter().map(|x| x << 2).collect::<Vec<_>>();
+        let mut bottom = self.height() + 3;
+        self.rock = (self.rock + 1) % ROCKS.len();
+
+        loop {
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+
+            if let Some(pushed) = Self::push(&rock, jet) {
+                if !self.collides(&pushed, bottom) {
+                    rock = pushed;
+                }
+            }
+
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (n, row) in rock.into_iter().enumerate() {
+            if bottom + n >= self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[bottom + n] |= row;
+        }
+    }
+
+    /// The depth of the highest rock in each column, measured from the top of the tower.
+    fn skyline(&self) -> [usize; CHAMBER_WIDTH] {
+        let mut skyline = [self.height(); CHAMBER_WIDTH];
+        for (column, depth) in skyline.iter_mut().enumerate() {
+            if let Some(n) = self.rows.iter().rev().position(|x| x & (1 << column) != 0) {
+                *depth = n;
+            }
+        }
+        skyline
+    }
+
+    pub fn tower_height(mut self, rocks: usize) -> usize {
+        let mut detector = CycleDetector::new();
+
+        for step in 0..rocks {
+            let state = (self.rock, self.jet, self.skyline());
+            if let Some(cycle) = detector.record(state, step, self.height() as i64) {
+                debug!(
+                    step,
+                    length = cycle.length,
+                    growth = cycle.delta,
+                    "found cycle"
+                );
+                let (remaining, skipped) = cycle.skip(step, rocks);
+                for _ in 0..remaining {
+                    self.drop_rock();
+                }
+                return self.height() + skipped as usize;
+            }
+            self.drop_rock();
+        }
+
+        self.height()
+    }
+}
+
+fn read_chamber(args: PartArgs) -> BoxResult<Chamber> {
+    let input = Input::try_from(args)?;
+    let jets = input
+        .bytes()
+        .iter()
+        .filter(|x| !x.is_ascii_whitespace())
+        .map(|x| Jet::try_from(*x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if jets.is_empty() {
+        return Err("no jets found in the input".into());
+    }
+
+    info!(jets = jets.len(), "parsed jet pattern");
+    Ok(Chamber::new(jets))
+}
+
+pub fn day_17() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_17")
+        .parts(&mut parts)
+        .about("the solution to the day 17 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let chamber = read_chamber(args)?;
+
+    Ok(CommandResponse::new(
+        chamber.tower_height(2022),
+        "The height of the tower after 2022 rocks is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let chamber = read_chamber(args)?;
+
+    Ok(CommandResponse::new(
+        chamber.tower_height(1_000_000_000_000),
+        "The height of the tower after 1000000000000 rocks is",
+    ))
+}
@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+
+use crate::{
+    helpers::voxel::{Point3, VoxelSet},
+    prelude::*,
+};
+
+fn read_droplet(args: PartArgs) -> BoxResult<VoxelSet> {
+    let input = Input::try_from(args)?;
+    let droplet = input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(Point3::try_from)
+        .collect::<Result<VoxelSet, _>>()?;
+
+    info!(cubes = droplet.len(), "parsed droplet");
+    Ok(droplet)
+}
+
+/// Fills the air around the droplet from just outside its bounding box, so pockets
+/// of air trapped inside the droplet are never reached.
+fn exterior(droplet: &VoxelSet) -> VoxelSet {
+    let mut air = VoxelSet::new();
+    let Some((min, max)) = droplet.bounds() else {
+        return air;
+    };
+    let (min, max) = (min + Point3::new(-1, -1, -1), max + Point3::new(1, 1, 1));
+
+    let mut queue = VecDeque::from([min]);
+    air.insert(min);
+
+    debug!(?min, ?max, "filling air around the droplet");
+    while let Some(point) = queue.pop_front() {
+        for next in point.neighbours() {
+            if next.within(min, max) && !droplet.contains(&next) && air.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    air
+}
+
+pub fn day_18() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_18")
+        .parts(&mut parts)
+        .about("the solution to the day 18 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let droplet = read_droplet(args)?;
+
+    Ok(CommandResponse::new(
+        droplet.surface_area(),
+        "The surface area of the droplet is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let droplet = read_droplet(args)?;
+    let air = exterior(&droplet);
+
+    let area = droplet
+        .iter()
+        .flat_map(|x| x.neighbours())
+        .filter(|x| air.contains(x))
+        .count();
+
+    Ok(CommandResponse::new(
+        area,
+        "The exterior surface area of the droplet is",
+    ))
+}
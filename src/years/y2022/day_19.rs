@@ -0,0 +1,225 @@
+use std::thread;
+
+use crate::prelude::*;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Blueprint {
+    id: u32,
+    // costs[robot][resource]
+    costs: [[u32; 3]; 4],
+    max_useful: [u32; 3],
+}
+
+impl TryFrom<&str> for Blueprint {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(s: &str) -> BoxResult<Self> {
+        let numbers = s
+            .split(|x: char| !x.is_ascii_digit())
+            .filter(|x| !x.is_empty())
+            .map(|x| x.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+            numbers[..]
+        else {
+            return Err(format!("invalid blueprint {}", s.trim()).into());
+        };
+
+        let costs = [
+            [ore_ore, 0, 0],
+            [clay_ore, 0, 0],
+            [obsidian_ore, obsidian_clay, 0],
+            [geode_ore, 0, geode_obsidian],
+        ];
+
+        let mut max_useful = [0; 3];
+        for cost in costs {
+            for resource in 0..3 {
+                max_useful[resource] = max_useful[resource].max(cost[resource]);
+            }
+        }
+
+        Ok(Self {
+            id,
+            costs,
+            max_useful,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    time: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+}
+
+impl Blueprint {
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        let start = State {
+            time: minutes,
+            robots: [1, 0, 0, 0],
+            resources: [0; 4],
+        };
+
+        let mut best = 0;
+        self.search(start, &mut best);
+        best
+    }
+
+    /// Minutes until the robot can be afforded with the current income, if ever.
+    fn wait_for(&self, robot: usize, state: &State) -> Option<u32> {
+        (0..3)
+            .map(|resource| {
+                let cost = self.costs[robot][resource];
+                let have = state.resources[resource];
+                if have >= cost {
+                    Some(0)
+                } else if state.robots[resource] == 0 {
+                    None
+                } else {
+                    Some((cost - have).div_ceil(state.robots[resource]))
+                }
+            })
+            .try_fold(0, |acc, x| Some(acc.max(x?)))
+    }
+
+    fn search(&self, state: State, best: &mut u32) {
+        let geodes = state.resources[GEODE] + state.robots[GEODE] * state.time;
+        *best = (*best).max(geodes);
+
+        // even building a geode robot every remaining minute can't beat the best
+        let bound = geodes + state.time * state.time.saturating_sub(1) / 2;
+        if bound <= *best {
+            return;
+        }
+
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robot != GEODE && state.robots[robot] >= self.max_useful[robot] {
+                continue;
+            }
+
+            let Some(wait) = self.wait_for(robot, &state) else {
+                continue;
+            };
+            if wait + 1 >= state.time {
+                continue;
+            }
+
+            let mut next = state;
+            next.time -= wait + 1;
+            for resource in 0..4 {
+                next.resources[resource] += next.robots[resource] * (wait + 1);
+            }
+            for resource in 0..3 {
+                next.resources[resource] -= self.costs[robot][resource];
+            }
+            next.robots[robot] += 1;
+
+            self.search(next, best);
+        }
+    }
+}
+
+fn read_blueprints(args: PartArgs) -> BoxResult<Vec<Blueprint>> {
+    let input = Input::try_from(args)?;
+
+    let blueprints = input
+        .text()
+        .split("Blueprint")
+        .filter(|x| !x.trim().is_empty())
+        .map(Blueprint::try_from)
+        .collect::<BoxResult<Vec<_>>>()?;
+
+    info!(blueprints = blueprints.len(), "parsed blueprints");
+    Ok(blueprints)
+}
+
+/// Evaluates every blueprint on its own thread, returning the results in order.
+fn max_geodes(blueprints: &[Blueprint], minutes: u32, progress: &Progress) -> Vec<u32> {
+    progress.set_total(blueprints.len() as u64);
+
+    thread::scope(|s| {
+        let handles = blueprints
+            .iter()
+            .map(|x| {
+                let progress = progress.clone();
+                s.spawn(move || {
+                    let geodes = x.max_geodes(minutes);
+                    progress.inc(1);
+                    geodes
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut most = 0;
+        handles
+            .into_iter()
+            .zip(blueprints)
+            .map(|(handle, blueprint)| {
+                let geodes = handle.join().unwrap();
+                debug!(blueprint = blueprint.id, geodes, "evaluated blueprint");
+                most = most.max(geodes);
+                progress.set_best(format!("{} geodes", most));
+                geodes
+            })
+            .collect()
+    })
+}
+
+pub fn day_19() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_19")
+        .parts(&mut parts)
+        .about("the solution to the day 19 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<u32>> {
+    let progress = args.progress();
+    let blueprints = read_blueprints(args)?;
+
+    let quality: u32 = blueprints
+        .iter()
+        .zip(max_geodes(&blueprints, 24, &progress))
+        .map(|(blueprint, geodes)| blueprint.id * geodes)
+        .sum();
+
+    Ok(CommandResponse::new(
+        quality,
+        "The sum of the blueprint quality levels is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<u32>> {
+    let progress = args.progress();
+    let blueprints = read_blueprints(args)?;
+    let first = &blueprints[..blueprints.len().min(3)];
+
+    let product: u32 = max_geodes(first, 32, &progress).into_iter().product();
+
+    Ok(CommandResponse::new(
+        product,
+        "The product of the geodes opened by the first three blueprints is",
+    ))
+}
@@ -98,7 +98,7 @@ pub fn day_2() -> BoxResult<DayCommand> {
         .build()
 }
 
-pub fn part_1(args: ArgMatches) -> BoxResult<()> {
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
     let f = FileReader::try_from(args)?;
 
     let mut score = 0;
@@ -123,12 +123,10 @@ pub fn part_1(args: ArgMatches) -> BoxResult<()> {
             }
     }
 
-    println!("The score for the player is {}", score);
-
-    Ok(())
+    Ok(CommandResponse::new(score, "The score for the player is"))
 }
 
-pub fn part_2(args: ArgMatches) -> BoxResult<()> {
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
     let f = FileReader::try_from(args)?;
 
     let mut score = 0;
@@ -153,7 +151,5 @@ pub fn part_2(args: ArgMatches) -> BoxResult<()> {
             _ => {}
         }
     }
-    println!("The score for the player is {}", score);
-
-    Ok(())
+    Ok(CommandResponse::new(score, "The score for the player is"))
 }
@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+/// Mixes the numbers, tracking each one by its original index so duplicate values
+/// are moved independently.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    let len = numbers.len() as i64 - 1;
+
+    for _ in 0..rounds {
+        for (index, value) in numbers.iter().enumerate() {
+            let position = order.iter().position(|x| *x == index).unwrap();
+            order.remove(position);
+            let target = (position as i64 + value).rem_euclid(len) as usize;
+            order.insert(target, index);
+        }
+    }
+
+    order.into_iter().map(|x| numbers[x]).collect()
+}
+
+fn grove_coordinates(mixed: &[i64]) -> BoxResult<i64> {
+    let zero = mixed
+        .iter()
+        .position(|x| *x == 0)
+        .ok_or("no zero found in the mixed numbers")?;
+
+    Ok([1000, 2000, 3000]
+        .iter()
+        .map(|x| mixed[(zero + x) % mixed.len()])
+        .sum())
+}
+
+fn read_numbers(args: PartArgs) -> BoxResult<Vec<i64>> {
+    let input = Input::try_from(args)?;
+    let numbers = input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if numbers.len() < 2 {
+        return Err("at least two numbers are needed to mix".into());
+    }
+
+    info!(numbers = numbers.len(), "parsed encrypted file");
+    Ok(numbers)
+}
+
+pub fn day_20() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_20")
+        .parts(&mut parts)
+        .about("the solution to the day 20 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i64>> {
+    let numbers = read_numbers(args)?;
+
+    let sum = grove_coordinates(&mix(&numbers, 1))?;
+
+    Ok(CommandResponse::new(
+        sum,
+        "The sum of the grove coordinates is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<i64>> {
+    let numbers = read_numbers(args)?
+        .into_iter()
+        .map(|x| x * DECRYPTION_KEY)
+        .collect::<Vec<_>>();
+
+    let sum = grove_coordinates(&mix(&numbers, 10))?;
+
+    Ok(CommandResponse::new(
+        sum,
+        "The sum of the decrypted grove coordinates is",
+    ))
+}
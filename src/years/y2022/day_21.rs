@@ -0,0 +1,314 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{error::MonkeyMathError, prelude::*};
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = &'static str;
+    fn try_from(s: &str) -> Result<Self, &'static str> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Sub),
+            "*" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            _ => Err("operator not found for monkey math"),
+        }
+    }
+}
+
+impl Operator {
+    fn apply(&self, lhs: i64, rhs: i64) -> Result<i64, MonkeyMathError> {
+        if let (Operator::Div, 0) = (self, rhs) {
+            return Err(MonkeyMathError::DivisionByZero(format!("{} / 0", lhs)));
+        }
+
+        let res = match self {
+            Operator::Add => lhs.checked_add(rhs),
+            Operator::Sub => lhs.checked_sub(rhs),
+            Operator::Mul => lhs.checked_mul(rhs),
+            Operator::Div => lhs.checked_div(rhs),
+        };
+        res.ok_or_else(|| MonkeyMathError::Overflow(format!("{} {:?} {}", lhs, self, rhs)))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+pub struct Monkeys {
+    jobs: HashMap<String, Job>,
+}
+
+impl TryFrom<Vec<String>> for Monkeys {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(lines: Vec<String>) -> BoxResult<Self> {
+        let mut jobs = HashMap::new();
+
+        for line in lines.iter().filter(|x| !x.is_empty()) {
+            let (name, job) = line
+                .split_once(": ")
+                .ok_or(format!("invalid monkey {}", line))?;
+
+            let job = match job.split_whitespace().collect::<Vec<_>>()[..] {
+                [number] => Job::Number(number.parse()?),
+                [lhs, op, rhs] => Job::Operation(lhs.into(), Operator::try_from(op)?, rhs.into()),
+                _ => return Err(format!("invalid job {}", job).into()),
+            };
+
+            jobs.insert(name.to_string(), job);
+        }
+
+        check_acyclic(&jobs)?;
+        Ok(Self { jobs })
+    }
+}
+
+/// Checks no monkey's number depends on itself, so evaluating and solving can recurse
+/// through the jobs without looping forever.
+fn check_acyclic(jobs: &HashMap<String, Job>) -> Result<(), MonkeyMathError> {
+    // names are in progress while their operands are being visited, then done
+    let mut in_progress = HashSet::new();
+    let mut done = HashSet::new();
+
+    for start in jobs.keys() {
+        let mut stack = vec![(start.as_str(), false)];
+        while let Some((name, finished)) = stack.pop() {
+            if finished {
+                in_progress.remove(name);
+                done.insert(name);
+                continue;
+            }
+            if done.contains(name) {
+                continue;
+            }
+            if !in_progress.insert(name) {
+                return Err(MonkeyMathError::Cycle(name.to_string()));
+            }
+
+            stack.push((name, true));
+            if let Some(Job::Operation(lhs, _, rhs)) = jobs.get(name) {
+                for operand in [lhs, rhs] {
+                    if in_progress.contains(operand.as_str()) {
+                        return Err(MonkeyMathError::Cycle(operand.to_string()));
+                    }
+                    stack.push((operand, false));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Monkeys {
+    fn job(&self, name: &str) -> Result<&Job, MonkeyMathError> {
+        self.jobs
+            .get(name)
+            .ok_or_else(|| MonkeyMathError::UnknownMonkey(name.to_string()))
+    }
+
+    pub fn eval(&self, name: &str) -> Result<i64, MonkeyMathError> {
+        self.eval_memo(name, &mut HashMap::new())
+    }
+
+    /// Evaluates `name`, keeping every number found in `values` so a monkey shared by
+    /// several others is only evaluated once.
+    fn eval_memo<'a>(
+        &'a self,
+        name: &'a str,
+        values: &mut HashMap<&'a str, i64>,
+    ) -> Result<i64, MonkeyMathError> {
+        if let Some(x) = values.get(name) {
+            return Ok(*x);
+        }
+
+        let res = match self.job(name)? {
+            Job::Number(n) => *n,
+            Job::Operation(lhs, op, rhs) => {
+                op.apply(self.eval_memo(lhs, values)?, self.eval_memo(rhs, values)?)?
+            }
+        };
+        values.insert(name, res);
+        Ok(res)
+    }
+
+    /// Returns the monkeys whose number depends on `target`, including `target` itself.
+    fn dependents<'a>(&'a self, target: &'a str) -> Result<HashSet<&'a str>, MonkeyMathError> {
+        let mut memo = HashMap::new();
+        for name in self.jobs.keys() {
+            self.depends_on(name, target, &mut memo)?;
+        }
+
+        let mut dependents = memo
+            .into_iter()
+            .filter(|(_, x)| *x)
+            .map(|(name, _)| name)
+            .collect::<HashSet<_>>();
+        dependents.insert(target);
+        Ok(dependents)
+    }
+
+    fn depends_on<'a>(
+        &'a self,
+        name: &'a str,
+        target: &str,
+        memo: &mut HashMap<&'a str, bool>,
+    ) -> Result<bool, MonkeyMathError> {
+        if name == target {
+            return Ok(true);
+        }
+        if let Some(x) = memo.get(name) {
+            return Ok(*x);
+        }
+
+        let res = match self.job(name)? {
+            Job::Number(_) => false,
+            Job::Operation(lhs, _, rhs) => {
+                // both sides are visited so every monkey below is memoised
+                let lhs = self.depends_on(lhs, target, memo)?;
+                self.depends_on(rhs, target, memo)? || lhs
+            }
+        };
+        memo.insert(name, res);
+        Ok(res)
+    }
+
+    /// Finds the value `unknown` must yell for `name` to yell `value`, inverting each
+    /// operation on the way down to it. `dependents` are the monkeys depending on `unknown`,
+    /// and `values` the numbers already evaluated for the others.
+    fn solve<'a>(
+        &'a self,
+        name: &'a str,
+        value: i64,
+        unknown: &str,
+        dependents: &HashSet<&str>,
+        values: &mut HashMap<&'a str, i64>,
+    ) -> Result<i64, MonkeyMathError> {
+        if name == unknown {
+            return Ok(value);
+        }
+
+        let Job::Operation(lhs, op, rhs) = self.job(name)? else {
+            return Err(MonkeyMathError::Unsolvable(name.to_string()));
+        };
+
+        let exact_div = |a: i64, b: i64| -> Result<i64, MonkeyMathError> {
+            if b == 0 || a % b != 0 {
+                return Err(MonkeyMathError::NoIntegerSolution(format!("{} / {}", a, b)));
+            }
+            Operator::Div.apply(a, b)
+        };
+
+        match (
+            dependents.contains(lhs.as_str()),
+            dependents.contains(rhs.as_str()),
+        ) {
+            (true, false) => {
+                let known = self.eval_memo(rhs, values)?;
+                let value = match op {
+                    Operator::Add => Operator::Sub.apply(value, known)?,
+                    Operator::Sub => Operator::Add.apply(value, known)?,
+                    Operator::Mul => exact_div(value, known)?,
+                    Operator::Div => Operator::Mul.apply(value, known)?,
+                };
+                self.solve(lhs, value, unknown, dependents, values)
+            }
+            (false, true) => {
+                let known = self.eval_memo(lhs, values)?;
+                let value = match op {
+                    Operator::Add => Operator::Sub.apply(value, known)?,
+                    Operator::Sub => Operator::Sub.apply(known, value)?,
+                    Operator::Mul => exact_div(value, known)?,
+                    Operator::Div => exact_div(known, value)?,
+                };
+                self.solve(rhs, value, unknown, dependents, values)
+            }
+            _ => Err(MonkeyMathError::Unsolvable(name.to_string())),
+        }
+    }
+
+    /// Solves for `unknown` so both sides of the monkey `name` are equal.
+    pub fn solve_equality(&self, name: &str, unknown: &str) -> Result<i64, MonkeyMathError> {
+        let Job::Operation(lhs, _, rhs) = self.job(name)? else {
+            return Err(MonkeyMathError::Unsolvable(name.to_string()));
+        };
+
+        let dependents = self.dependents(unknown)?;
+        let mut values = HashMap::new();
+        match (
+            dependents.contains(lhs.as_str()),
+            dependents.contains(rhs.as_str()),
+        ) {
+            (true, false) => {
+                let value = self.eval_memo(rhs, &mut values)?;
+                self.solve(lhs, value, unknown, &dependents, &mut values)
+            }
+            (false, true) => {
+                let value = self.eval_memo(lhs, &mut values)?;
+                self.solve(rhs, value, unknown, &dependents, &mut values)
+            }
+            _ => Err(MonkeyMathError::Unsolvable(name.to_string())),
+        }
+    }
+}
+
+fn read_monkeys(args: PartArgs) -> BoxResult<Monkeys> {
+    let f = FileReader::try_from(args)?;
+    let monkeys = Monkeys::try_from(f.collect::<Vec<_>>())?;
+
+    info!(monkeys = monkeys.jobs.len(), "parsed monkeys");
+    Ok(monkeys)
+}
+
+pub fn day_21() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_21")
+        .parts(&mut parts)
+        .about("the solution to the day 21 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i64>> {
+    let monkeys = read_monkeys(args)?;
+
+    Ok(CommandResponse::new(
+        monkeys.eval(ROOT)?,
+        "The monkey named root yells",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<i64>> {
+    let monkeys = read_monkeys(args)?;
+
+    Ok(CommandResponse::new(
+        monkeys.solve_equality(ROOT, HUMAN)?,
+        "The number to yell to pass the equality test is",
+    ))
+}
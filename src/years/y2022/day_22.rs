@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+const VOID: u8 = b' ';
+const WALL: u8 = b'#';
+
+// facing values as scored by the puzzle: right, down, left, up
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+type State = (usize, usize, usize);
+
+#[derive(Debug, Clone, Copy)]
+enum Move {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+fn parse_path(s: &str) -> BoxResult<Vec<Move>> {
+    let mut moves = Vec::new();
+    let mut steps = String::new();
+
+    for c in s.trim().chars() {
+        if c.is_ascii_digit() {
+            steps.push(c);
+            continue;
+        }
+        if !steps.is_empty() {
+            moves.push(Move::Forward(steps.parse()?));
+            steps.clear();
+        }
+        moves.push(match c {
+            'L' => Move::Left,
+            'R' => Move::Right,
+            _ => return Err(format!("unknown turn {} in path", c).into()),
+        });
+    }
+    if !steps.is_empty() {
+        moves.push(Move::Forward(steps.parse()?));
+    }
+
+    Ok(moves)
+}
+
+pub struct Board {
+    tiles: Vec<Vec<u8>>,
+    path: Vec<Move>,
+}
+
+impl TryFrom<&str> for Board {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(s: &str) -> BoxResult<Self> {
+        let lines = s
+            .lines()
+            .map(|x| x.trim_end_matches('\r'))
+            .collect::<Vec<_>>();
+        let split = lines
+            .iter()
+            .position(|x| x.trim().is_empty())
+            .ok_or("no path found after the board")?;
+
+        let width = lines[..split]
+            .iter()
+            .map(|x| x.len())
+            .max()
+            .unwrap_or_default();
+        let tiles = lines[..split]
+            .iter()
+            .map(|x| {
+                let mut row = x.as_bytes().to_vec();
+                row.resize(width, VOID);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        if tiles
+            .iter()
+            .flatten()
+            .any(|x| ![VOID, WALL, b'.'].contains(x))
+        {
+            return Err("board contains an unknown tile".into());
+        }
+
+        let path = parse_path(&lines[split..].concat())?;
+
+        Ok(Self { tiles, path })
+    }
+}
+
+impl Board {
+    fn tile(&self, row: isize, col: isize) -> u8 {
+        if row < 0 || col < 0 {
+            return VOID;
+        }
+        self.tiles
+            .get(row as usize)
+            .and_then(|x| x.get(col as usize))
+            .copied()
+            .unwrap_or(VOID)
+    }
+
+    fn start(&self) -> BoxResult<State> {
+        let col = self.tiles[0]
+            .iter()
+            .position(|x| *x == b'.')
+            .ok_or("no open tile on the top row")?;
+        Ok((0, col, 0))
+    }
+
+    /// Follows the path, calling `wrap` to find where a step off the board lands.
+    pub fn walk(&self, wrap: impl Fn(State) -> State) -> BoxResult<usize> {
+        let (mut row, mut col, mut facing) = self.start()?;
+
+        for step in &self.path {
+            match step {
+                Move::Left => facing = (facing + 3) % 4,
+                Move::Right => facing = (facing + 1) % 4,
+                Move::Forward(n) => {
+                    for _ in 0..*n {
+                        let (dr, dc) = DIRECTIONS[facing];
+                        let (r, c) = (row as isize + dr, col as isize + dc);
+
+                        let next = match self.tile(r, c) {
+                            VOID => wrap((row, col, facing)),
+                            _ => (r as usize, c as usize, facing),
+                        };
+
+                        if self.tiles[next.0][next.1] == WALL {
+                            break;
+                        }
+                        (row, col, facing) = next;
+                    }
+                }
+            }
+        }
+
+        Ok(1000 * (row + 1) + 4 * (col + 1) + facing)
+    }
+
+    /// Wraps to the far side of the board in the same row or column.
+    pub fn flat_wrap(&self, (row, col, facing): State) -> State {
+        let (dr, dc) = DIRECTIONS[facing];
+        let (mut r, mut c) = (row as isize, col as isize);
+
+        while self.tile(r - dr, c - dc) != VOID {
+            (r, c) = (r - dr, c - dc);
+        }
+
+        (r as usize, c as usize, facing)
+    }
+}
+
+type Vector = [i64; 3];
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: Vector, n: i64) -> Vector {
+    [a[0] * n, a[1] * n, a[2] * n]
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Where a face of the net ends up once folded: the corner its top left tile
+/// touches and the directions its columns, rows and outward normal point in.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    origin: Vector,
+    right: Vector,
+    down: Vector,
+    normal: Vector,
+}
+
+impl Face {
+    fn directions(&self) -> [Vector; 4] {
+        [
+            self.right,
+            self.down,
+            scale(self.right, -1),
+            scale(self.down, -1),
+        ]
+    }
+}
+
+/// The board folded into a cube. Positions on the cube are doubled so that tile
+/// centres and cube edges both fall on integer coordinates.
+pub struct Cube {
+    size: usize,
+    faces: HashMap<(usize, usize), Face>,
+}
+
+impl TryFrom<&Board> for Cube {
+    type Error = Box<dyn std::error::Error>;
+    fn try_from(board: &Board) -> BoxResult<Self> {
+        let area = board.tiles.iter().flatten().filter(|x| **x != VOID).count();
+        let size = ((area / 6) as f64).sqrt() as usize;
+        if size == 0 || size * size * 6 != area {
+            return Err("board can not be folded into a cube".into());
+        }
+
+        let is_face =
+            |(r, c): (usize, usize)| board.tile((r * size) as isize, (c * size) as isize) != VOID;
+        let (_, start_col, _) = board.start()?;
+
+        let mut faces = HashMap::new();
+        let mut stack = vec![(
+            (0_usize, start_col / size),
+            Face {
+                origin: [0, 0, 0],
+                right: [1, 0, 0],
+                down: [0, 1, 0],
+                normal: [0, 0, -1],
+            },
+        )];
+        let edge = 2 * size as i64;
+
+        while let Some(((r, c), face)) = stack.pop() {
+            if faces.contains_key(&(r, c)) {
+                continue;
+            }
+            faces.insert((r, c), face);
+
+            let inward = scale(face.normal, -1);
+            let folds = [
+                (
+                    (r, c + 1),
+                    Face {
+                        origin: add(face.origin, scale(face.right, edge)),
+                        right: inward,
+                        down: face.down,
+                        normal: face.right,
+                    },
+                ),
+                (
+                    (r + 1, c),
+                    Face {
+                        origin: add(face.origin, scale(face.down, edge)),
+                        right: face.right,
+                        down: inward,
+                        normal: face.down,
+                    },
+                ),
+                (
+                    (r, c.wrapping_sub(1)),
+                    Face {
+                        origin: add(face.origin, scale(inward, edge)),
+                        right: face.normal,
+                        down: face.down,
+                        normal: scale(face.right, -1),
+                    },
+                ),
+                (
+                    (r.wrapping_sub(1), c),
+                    Face {
+                        origin: add(face.origin, scale(inward, edge)),
+                        right: face.right,
+                        down: face.normal,
+                        normal: scale(face.down, -1),
+                    },
+                ),
+            ];
+
+            for (position, face) in folds {
+                if position.0 != usize::MAX && position.1 != usize::MAX && is_face(position) {
+                    stack.push((position, face));
+                }
+            }
+        }
+
+        if faces.len() != 6 {
+            return Err(
+                format!("cube net has {} connected faces instead of 6", faces.len()).into(),
+            );
+        }
+
+        debug!(size, "folded the board into a cube");
+        Ok(Self { size, faces })
+    }
+}
+
+impl Cube {
+    /// Steps off the edge of a face onto the face that shares that edge once folded.
+    pub fn wrap(&self, (row, col, facing): State) -> State {
+        let face = self.faces[&(row / self.size, col / self.size)];
+        let (local_row, local_col) = ((row % self.size) as i64, (col % self.size) as i64);
+
+        let centre = add(
+            face.origin,
+            add(
+                scale(face.right, 2 * local_col + 1),
+                scale(face.down, 2 * local_row + 1),
+            ),
+        );
+        let direction = face.directions()[facing];
+        let next = add(add(centre, direction), scale(face.normal, -1));
+
+        let (&(face_row, face_col), next_face) = self
+            .faces
+            .iter()
+            .find(|(_, x)| x.normal == direction)
+            .unwrap();
+
+        let relative = add(next, scale(next_face.origin, -1));
+        let local_col = (dot(relative, next_face.right) - 1) / 2;
+        let local_row = (dot(relative, next_face.down) - 1) / 2;
+        let facing = next_face
+            .directions()
+            .iter()
+            .position(|x| *x == scale(face.normal, -1))
+            .unwrap();
+
+        (
+            face_row * self.size + local_row as usize,
+            face_col * self.size + local_col as usize,
+            facing,
+        )
+    }
+}
+
+fn read_board(args: PartArgs) -> BoxResult<Board> {
+    let board = Board::try_from(Input::try_from(args)?.text())?;
+
+    info!(
+        rows = board.tiles.len(),
+        columns = board.tiles[0].len(),
+        moves = board.path.len(),
+        "parsed board"
+    );
+    Ok(board)
+}
+
+pub fn day_22() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_22")
+        .parts(&mut parts)
+        .about("the solution to the day 22 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let board = read_board(args)?;
+
+    let password = board.walk(|x| board.flat_wrap(x))?;
+
+    Ok(CommandResponse::new(password, "The final password is"))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let board = read_board(args)?;
+    let cube = Cube::try_from(&board)?;
+
+    let password = board.walk(|x| cube.wrap(x))?;
+
+    Ok(CommandResponse::new(
+        password,
+        "The final password on the cube is",
+    ))
+}
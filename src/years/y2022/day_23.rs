@@ -0,0 +1,151 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::prelude::*;
+
+type Point = (i32, i32);
+
+// each direction with the three neighbours that must be empty to propose moving that way
+const DIRECTIONS: [(Point, [Point; 3]); 4] = [
+    ((0, -1), [(-1, -1), (0, -1), (1, -1)]),
+    ((0, 1), [(-1, 1), (0, 1), (1, 1)]),
+    ((-1, 0), [(-1, -1), (-1, 0), (-1, 1)]),
+    ((1, 0), [(1, -1), (1, 0), (1, 1)]),
+];
+
+pub struct Grove {
+    elves: HashSet<Point>,
+    round: usize,
+}
+
+impl TryFrom<Vec<String>> for Grove {
+    type Error = &'static str;
+    fn try_from(lines: Vec<String>) -> Result<Self, &'static str> {
+        let mut elves = HashSet::new();
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        elves.insert((x as i32, y as i32));
+                    }
+                    '.' => {}
+                    _ => return Err("grove contains an unknown tile"),
+                }
+            }
+        }
+
+        Ok(Self { elves, round: 0 })
+    }
+}
+
+impl Grove {
+    fn is_occupied(&self, (x, y): Point, (dx, dy): Point) -> bool {
+        self.elves.contains(&(x + dx, y + dy))
+    }
+
+    fn propose(&self, elf: Point) -> Option<Point> {
+        let alone = DIRECTIONS
+            .iter()
+            .flat_map(|(_, checks)| checks)
+            .all(|x| !self.is_occupied(elf, *x));
+        if alone {
+            return None;
+        }
+
+        (0..DIRECTIONS.len())
+            .map(|n| DIRECTIONS[(self.round + n) % DIRECTIONS.len()])
+            .find(|(_, checks)| checks.iter().all(|x| !self.is_occupied(elf, *x)))
+            .map(|((dx, dy), _)| (elf.0 + dx, elf.1 + dy))
+    }
+
+    /// Runs a single round, returning whether any elf moved.
+    pub fn step(&mut self) -> bool {
+        let mut proposals: HashMap<Point, Vec<Point>> = HashMap::new();
+        for elf in &self.elves {
+            if let Some(target) = self.propose(*elf) {
+                proposals.entry(target).or_default().push(*elf);
+            }
+        }
+
+        let mut moved = false;
+        for (target, elves) in proposals {
+            if let [elf] = elves[..] {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+
+        self.round += 1;
+        moved
+    }
+
+    pub fn empty_ground(&self) -> usize {
+        let min_x = self.elves.iter().map(|x| x.0).min().unwrap_or_default();
+        let max_x = self.elves.iter().map(|x| x.0).max().unwrap_or_default();
+        let min_y = self.elves.iter().map(|x| x.1).min().unwrap_or_default();
+        let max_y = self.elves.iter().map(|x| x.1).max().unwrap_or_default();
+
+        ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize - self.elves.len()
+    }
+}
+
+fn read_grove(args: PartArgs) -> BoxResult<Grove> {
+    let f = FileReader::try_from(args)?;
+    let grove = Grove::try_from(f.collect::<Vec<_>>())?;
+
+    // the bounding rectangle of no elves is not defined
+    if grove.elves.is_empty() {
+        return Err("no elves found in the grove".into());
+    }
+
+    info!(elves = grove.elves.len(), "parsed grove");
+    Ok(grove)
+}
+
+pub fn day_23() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_23")
+        .parts(&mut parts)
+        .about("the solution to the day 23 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let mut grove = read_grove(args)?;
+
+    for _ in 0..10 {
+        grove.step();
+    }
+
+    Ok(CommandResponse::new(
+        grove.empty_ground(),
+        "The empty ground tiles after 10 rounds are",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let mut grove = read_grove(args)?;
+
+    while grove.step() {}
+
+    Ok(CommandResponse::new(
+        grove.round,
+        "The first round where no elf moves is",
+    ))
+}
@@ -0,0 +1,193 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::prelude::*;
+
+type Point = (usize, usize);
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub struct Valley {
+    walls: Vec<Vec<bool>>,
+    // blocked[t][row][col] for the inner valley, repeating every period minutes
+    blocked: Vec<Vec<Vec<bool>>>,
+    start: Point,
+    goal: Point,
+}
+
+impl TryFrom<Vec<String>> for Valley {
+    type Error = &'static str;
+    fn try_from(lines: Vec<String>) -> Result<Self, &'static str> {
+        let lines = lines.iter().filter(|x| !x.is_empty()).collect::<Vec<_>>();
+        if lines.len() < 3 || lines[0].len() < 3 {
+            return Err("valley is too small");
+        }
+
+        let (rows, cols) = (lines.len(), lines[0].len());
+        let (height, width) = (rows - 2, cols - 2);
+        let period = height / gcd(height, width) * width;
+
+        let mut walls = vec![vec![false; cols]; rows];
+        let mut blocked = vec![vec![vec![false; width]; height]; period];
+
+        for (row, line) in lines.iter().enumerate() {
+            if line.len() != cols {
+                return Err("valley rows have different widths");
+            }
+
+            for (col, c) in line.chars().enumerate() {
+                let (dr, dc): (isize, isize) = match c {
+                    '#' => {
+                        walls[row][col] = true;
+                        continue;
+                    }
+                    '.' => continue,
+                    '>' => (0, 1),
+                    '<' => (0, -1),
+                    'v' => (1, 0),
+                    '^' => (-1, 0),
+                    _ => return Err("valley contains an unknown tile"),
+                };
+
+                if row == 0 || col == 0 || row == rows - 1 || col == cols - 1 {
+                    return Err("blizzard found outside of the valley");
+                }
+
+                for (t, minute) in blocked.iter_mut().enumerate() {
+                    let r = (row as isize - 1 + dr * t as isize).rem_euclid(height as isize);
+                    let c = (col as isize - 1 + dc * t as isize).rem_euclid(width as isize);
+                    minute[r as usize][c as usize] = true;
+                }
+            }
+        }
+
+        let opening = |row: usize| -> Result<Point, &'static str> {
+            let col = walls[row]
+                .iter()
+                .position(|x| !x)
+                .ok_or("no opening found in the valley wall")?;
+            Ok((row, col))
+        };
+
+        Ok(Self {
+            start: opening(0)?,
+            goal: opening(rows - 1)?,
+            walls,
+            blocked,
+        })
+    }
+}
+
+impl Valley {
+    fn is_free(&self, (row, col): Point, time: usize) -> bool {
+        if self.walls[row][col] {
+            return false;
+        }
+        if row == 0 || row == self.walls.len() - 1 {
+            return true;
+        }
+        !self.blocked[time % self.blocked.len()][row - 1][col - 1]
+    }
+
+    /// Searches over (position, time) for the earliest minute `to` can be reached
+    /// when leaving `from` at minute `time`.
+    pub fn fastest(&self, from: Point, to: Point, time: usize) -> Option<usize> {
+        let period = self.blocked.len();
+        let mut seen = HashSet::from([(from, time % period)]);
+        let mut queue = VecDeque::from([(from, time)]);
+
+        while let Some(((row, col), time)) = queue.pop_front() {
+            if (row, col) == to {
+                return Some(time);
+            }
+
+            let moves = [(row, col), (row + 1, col), (row, col + 1)]
+                .into_iter()
+                .chain(row.checked_sub(1).map(|r| (r, col)))
+                .chain(col.checked_sub(1).map(|c| (row, c)));
+
+            for next in moves {
+                if next.0 >= self.walls.len() || next.1 >= self.walls[0].len() {
+                    continue;
+                }
+                if self.is_free(next, time + 1) && seen.insert((next, (time + 1) % period)) {
+                    queue.push_back((next, time + 1));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn read_valley(args: PartArgs) -> BoxResult<Valley> {
+    let f = FileReader::try_from(args)?;
+    let valley = Valley::try_from(f.collect::<Vec<_>>())?;
+
+    info!(
+        rows = valley.walls.len(),
+        columns = valley.walls[0].len(),
+        period = valley.blocked.len(),
+        "parsed valley"
+    );
+    Ok(valley)
+}
+
+pub fn day_24() -> BoxResult<DayCommand> {
+    let mut parts = vec![
+        PartBuilder::new()
+            .name("part_1")
+            .short_flag('1')
+            .about("part 1 of the challenge")
+            .func(part_1)
+            .build()?,
+        PartBuilder::new()
+            .name("part_2")
+            .short_flag('2')
+            .about("part 2 of the challenge")
+            .func(part_2)
+            .build()?,
+    ];
+
+    DayCommandBuilder::new()
+        .name("day_24")
+        .parts(&mut parts)
+        .about("the solution to the day 24 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let valley = read_valley(args)?;
+
+    let time = valley
+        .fastest(valley.start, valley.goal, 0)
+        .ok_or("no way through the valley")?;
+
+    Ok(CommandResponse::new(
+        time,
+        "The fewest minutes to reach the goal is",
+    ))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<usize>> {
+    let valley = read_valley(args)?;
+
+    let time = [
+        (valley.start, valley.goal),
+        (valley.goal, valley.start),
+        (valley.start, valley.goal),
+    ]
+    .iter()
+    .try_fold(0, |time, (from, to)| valley.fastest(*from, *to, time))
+    .ok_or("no way through the valley")?;
+
+    Ok(CommandResponse::new(
+        time,
+        "The fewest minutes to reach the goal, go back and reach it again is",
+    ))
+}
@@ -0,0 +1,122 @@
+use std::{fmt::Display, iter::Sum};
+
+use crate::prelude::*;
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Eq, Ord)]
+pub struct Snafu {
+    value: i64,
+}
+
+impl TryFrom<&str> for Snafu {
+    type Error = &'static str;
+    fn try_from(s: &str) -> Result<Self, &'static str> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("snafu number is empty");
+        }
+
+        let mut value: i64 = 0;
+        for c in s.chars() {
+            let digit = match c {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err("char not found for snafu digit"),
+            };
+            value = value
+                .checked_mul(5)
+                .and_then(|x| x.checked_add(digit))
+                .ok_or("snafu number is too large")?;
+        }
+
+        Ok(Self { value })
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<Snafu> for i64 {
+    fn from(snafu: Snafu) -> Self {
+        snafu.value
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.value == 0 {
+            return write!(f, "0");
+        }
+
+        // i128 so the carry on the most negative and positive values can't overflow
+        let mut value = self.value as i128;
+        let mut digits = Vec::new();
+        while value != 0 {
+            let (digit, carry) = match value.rem_euclid(5) {
+                0 => ('0', 0),
+                1 => ('1', 0),
+                2 => ('2', 0),
+                3 => ('=', 1),
+                _ => ('-', 1),
+            };
+            digits.push(digit);
+            value = value.div_euclid(5) + carry;
+        }
+
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+// summing into an Option so a total too large for an i64 is None instead of wrapping
+impl Sum<Snafu> for Option<Snafu> {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Self {
+        let mut total = Snafu::new();
+        for i in iter {
+            total.value = total.value.checked_add(i.value)?;
+        }
+        Some(total)
+    }
+}
+
+impl Snafu {
+    fn new() -> Self {
+        Self { value: 0 }
+    }
+}
+
+pub fn day_25() -> BoxResult<DayCommand> {
+    let mut parts = vec![PartBuilder::new()
+        .name("part_1")
+        .short_flag('1')
+        .about("part 1 of the challenge")
+        .func(part_1)
+        .build()?];
+
+    DayCommandBuilder::new()
+        .name("day_25")
+        .parts(&mut parts)
+        .about("the solution to the day 25 advent of code problem")
+        .build()
+}
+
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<Snafu>> {
+    let input = Input::try_from(args)?;
+
+    let total = input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(Snafu::try_from)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .sum::<Option<Snafu>>()
+        .ok_or("sum of snafu numbers is too large")?;
+
+    info!(decimal = i64::from(total), "summed fuel requirements");
+
+    Ok(CommandResponse::new(total, "The SNAFU number to enter is"))
+}
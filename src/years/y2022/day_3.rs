@@ -100,7 +100,8 @@ pub fn day_3() -> BoxResult<DayCommand> {
             .name("part 1")
             .about("part 1 of the problem")
             .short_flag('1')
-            .func(part_1)
+            .variant("naive", part_1)
+            .variant("bitset", part_1_bitset)
             .build()?,
         PartBuilder::new()
             .name("part 2")
@@ -117,7 +118,7 @@ pub fn day_3() -> BoxResult<DayCommand> {
         .build()
 }
 
-pub fn part_1(args: ArgMatches) -> BoxResult<()> {
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
     let f = FileReader::try_from(args)?;
     let mut rucksacks = Vec::new();
 
@@ -125,14 +126,47 @@ pub fn part_1(args: ArgMatches) -> BoxResult<()> {
         rucksacks.push(Rucksack::try_from(i.to_owned())?);
     }
 
-    println!(
-        "The sum of priorities is {}",
-        rucksacks.iter().map(|x| x.priority).sum::<i32>()
-    );
-    Ok(())
+    info!(rucksacks = rucksacks.len(), "parsed rucksacks");
+
+    Ok(CommandResponse::new(
+        rucksacks.iter().map(|x| x.priority).sum::<i32>(),
+        "The sum of priorities is",
+    ))
+}
+
+/// Returns the set of items in a compartment, with each item's bit at its priority.
+fn item_set(items: &str) -> Result<u64, &'static str> {
+    items.bytes().try_fold(0, |acc, x| {
+        let priority = match x {
+            b'a'..=b'z' => x - b'a' + 1,
+            b'A'..=b'Z' => x - b'A' + 27,
+            _ => return Err("char not found for rucksack item"),
+        };
+        Ok(acc | 1 << priority)
+    })
 }
 
-pub fn part_2(args: ArgMatches) -> BoxResult<()> {
+pub fn part_1_bitset(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
+    let input = Input::try_from(args)?;
+    let mut total = 0;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            return Err("Rucksack is empty!!".into());
+        }
+
+        let (comp1, comp2) = line.split_at(line.len() / 2);
+        let mut shared = item_set(comp1)? & item_set(comp2)?;
+        while shared != 0 {
+            total += shared.trailing_zeros() as i32;
+            shared &= shared - 1;
+        }
+    }
+
+    Ok(CommandResponse::new(total, "The sum of priorities is"))
+}
+
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
     let f = FileReader::try_from(args)?;
     let mut groups = Vec::new();
     let mut buf = Vec::new();
@@ -145,9 +179,10 @@ pub fn part_2(args: ArgMatches) -> BoxResult<()> {
         }
     }
 
-    println!(
-        "priorty of all the groups is {}",
-        groups.iter().map(|x| x.priority).sum::<i32>()
-    );
-    Ok(())
+    info!(groups = groups.len(), "parsed groups");
+
+    Ok(CommandResponse::new(
+        groups.iter().map(|x| x.priority).sum::<i32>(),
+        "priorty of all the groups is",
+    ))
 }
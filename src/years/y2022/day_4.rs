@@ -58,7 +58,7 @@ pub fn day_4() -> BoxResult<DayCommand> {
         .build()
 }
 
-pub fn part_1(args: ArgMatches) -> BoxResult<()> {
+pub fn part_1(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
     let f = FileReader::try_from(args)?;
 
     let mut count = 0;
@@ -77,17 +77,13 @@ pub fn part_1(args: ArgMatches) -> BoxResult<()> {
         }
     }
 
-    println!("Found {} contained tasks in {} pairs", count, total);
+    info!(pairs = total, "parsed pairs");
 
-    Ok(())
+    Ok(CommandResponse::new(count, "The number of pairs where one task contains the other is"))
 }
 
-pub fn part_2(args: ArgMatches) -> BoxResult<()> {
-    let path: String = args.get_one::<String>("file").unwrap().to_owned();
-
-    let path = Path::new(&path);
-
-    let f = File::open(path)?;
+pub fn part_2(args: PartArgs) -> BoxResult<CommandResponse<i32>> {
+    let f = File::open(args.file())?;
     let mut reader = BufReader::new(f);
     let mut line = String::new();
     let mut not_eof: bool = reader.read_line(&mut line)? != 0;
@@ -112,7 +108,7 @@ pub fn part_2(args: ArgMatches) -> BoxResult<()> {
         }
     }
 
-    println!("Found {} overlapping tasks in {} pairs", count, total);
+    info!(pairs = total, "parsed pairs");
 
-    Ok(())
+    Ok(CommandResponse::new(count, "The number of pairs with overlapping tasks is"))
 }
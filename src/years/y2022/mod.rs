@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+mod day_1;
+mod day_2;
+mod day_3;
+mod day_4;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+use day_1::*;
+use day_2::*;
+use day_3::*;
+use day_4::*;
+use day_10::*;
+use day_11::*;
+use day_12::*;
+use day_13::*;
+use day_14::*;
+use day_15::*;
+use day_16::*;
+use day_17::*;
+use day_18::*;
+use day_19::*;
+use day_20::*;
+use day_21::*;
+use day_22::*;
+use day_23::*;
+use day_24::*;
+use day_25::*;
+
+pub fn year_2022() -> BoxResult<YearCommand> {
+    let mut days = vec![
+        day_1()?,
+        day_2()?,
+        day_3()?,
+        day_4()?,
+        day_10()?,
+        day_11()?,
+        day_12()?,
+        day_13()?,
+        day_14()?,
+        day_15()?,
+        day_16()?,
+        day_17()?,
+        day_18()?,
+        day_19()?,
+        day_20()?,
+        day_21()?,
+        day_22()?,
+        day_23()?,
+        day_24()?,
+        day_25()?,
+    ];
+
+    YearCommandBuilder::new()
+        .name("2022")
+        .days(&mut days)
+        .about("the solutions to the 2022 advent of code problems")
+        .build()
+}